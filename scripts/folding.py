@@ -0,0 +1,56 @@
+"""Generates `src/folding.rs` from `CaseFolding.txt`.
+
+Usage: python scripts/folding.py path/to/CaseFolding.txt > src/folding.rs
+"""
+
+import sys
+
+FULL = ("C", "F")
+
+
+def main() -> None:
+    (path,) = sys.argv[1:]
+
+    with open(path, encoding="utf-8") as file:
+        lines = file.read().splitlines()
+
+    version = lines[0].removeprefix("# CaseFolding-").removesuffix(".txt")
+
+    mappings = []
+
+    for line in lines:
+        data, _, _ = line.partition("#")
+
+        if not data.strip():
+            continue
+
+        code, status, mapping, _ = (part.strip() for part in data.split(";"))
+
+        if status not in FULL:
+            continue
+
+        characters = [int(part, 16) for part in mapping.split()]
+
+        characters.extend([0] * (3 - len(characters)))
+
+        mappings.append((int(code, 16), characters))
+
+    mappings.sort()
+
+    print("//! Full case folding data.")
+    print()
+    print(f"// NOTE: generated by `scripts/folding.py` from `CaseFolding-{version}.txt`,")
+    print("// using common and full mappings, padded with `\\0`; do not edit manually")
+    print()
+    print("pub(crate) const CASE_FOLDING: &[(char, [char; 3])] = &[")
+
+    for code, characters in mappings:
+        folded = ", ".join(f"'\\u{{{character:x}}}'" for character in characters)
+
+        print(f"    ('\\u{{{code:x}}}', [{folded}]),")
+
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,72 @@
+#[cfg(not(feature = "arbitrary"))]
+compile_error!("expected `arbitrary` to be enabled");
+
+use arbitrary::{Arbitrary, Error, Result, Unstructured, size_hint};
+
+use crate::{boxed::NonEmptyBoxedStr, str::NonEmptyStr, string::NonEmptyString};
+
+impl<'a> Arbitrary<'a> for &'a NonEmptyStr {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        let string = <&str>::arbitrary(unstructured)?;
+
+        NonEmptyStr::try_from_str(string).map_err(|_| Error::IncorrectFormat)
+    }
+
+    fn arbitrary_take_rest(unstructured: Unstructured<'a>) -> Result<Self> {
+        let string = <&str>::arbitrary_take_rest(unstructured)?;
+
+        NonEmptyStr::try_from_str(string).map_err(|_| Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
+
+// NOTE: owned strings start with an arbitrary character, so that generating them never fails
+// due to emptiness, unlike borrowed strings that have to be taken from the data as-is
+
+impl<'a> Arbitrary<'a> for NonEmptyString {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        let character = char::arbitrary(unstructured)?;
+
+        let rest = <&str>::arbitrary(unstructured)?;
+
+        Ok(from_parts(character, rest))
+    }
+
+    fn arbitrary_take_rest(mut unstructured: Unstructured<'a>) -> Result<Self> {
+        let character = char::arbitrary(&mut unstructured)?;
+
+        let rest = <&str>::arbitrary_take_rest(unstructured)?;
+
+        Ok(from_parts(character, rest))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(char::size_hint(depth), <&str>::size_hint(depth))
+    }
+}
+
+fn from_parts(character: char, rest: &str) -> NonEmptyString {
+    let mut string = NonEmptyString::single(character);
+
+    string.push_str(rest);
+
+    string
+}
+
+impl<'a> Arbitrary<'a> for NonEmptyBoxedStr {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmptyString::arbitrary(unstructured).map(NonEmptyString::into_non_empty_boxed_str)
+    }
+
+    fn arbitrary_take_rest(unstructured: Unstructured<'a>) -> Result<Self> {
+        NonEmptyString::arbitrary_take_rest(unstructured)
+            .map(NonEmptyString::into_non_empty_boxed_str)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        NonEmptyString::size_hint(depth)
+    }
+}
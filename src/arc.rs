@@ -0,0 +1,164 @@
+//! Non-empty [`Arc<str>`].
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{string::String, sync::Arc};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{string::String, sync::Arc};
+
+use non_empty_iter::{FromNonEmptyIterator, IntoNonEmptyIterator};
+use thiserror::Error;
+
+use crate::{
+    boxed::NonEmptyBoxedStr,
+    str::NonEmptyStr,
+    string::{EmptyString, NonEmptyString},
+};
+
+/// The error message used when the atomically reference-counted string is empty.
+pub const EMPTY_ARC_STR: &str = "the atomically reference-counted string is empty";
+
+/// Similar to [`EmptyBoxedStr`], but contains the empty
+/// atomically reference-counted string provided.
+///
+/// [`EmptyBoxedStr`]: crate::boxed::EmptyBoxedStr
+#[derive(Debug, Error)]
+#[error("{EMPTY_ARC_STR}")]
+pub struct EmptyArcStr {
+    arc: Arc<str>,
+}
+
+impl EmptyArcStr {
+    // NOTE: this is private to prevent creating this error with non-empty strings
+    pub(crate) const fn new(arc: Arc<str>) -> Self {
+        Self { arc }
+    }
+
+    /// Returns the contained empty reference-counted string.
+    #[must_use]
+    pub fn get(self) -> Arc<str> {
+        self.arc
+    }
+
+    /// Constructs [`Self`] from [`EmptyString`].
+    #[must_use]
+    pub fn from_empty_string(empty: EmptyString) -> Self {
+        Self::new(Arc::from(empty.get()))
+    }
+
+    /// Converts [`Self`] into [`EmptyString`].
+    #[must_use]
+    pub fn into_empty_string(self) -> EmptyString {
+        EmptyString::new(String::from(&*self.arc))
+    }
+}
+
+/// Represents non-empty atomically reference-counted strings, [`Arc<NonEmptyStr>`].
+///
+/// Serialization and deserialization via `serde` require the `serde-rc` feature.
+pub type NonEmptyArcStr = Arc<NonEmptyStr>;
+
+// NOTE: `TryFrom<Arc<str>>` can not be implemented for `NonEmptyArcStr` due to orphan rules,
+// which is why `NonEmptyStr::from_arc_str` should be used instead
+
+impl From<NonEmptyString> for NonEmptyArcStr {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_non_empty_arc_str()
+    }
+}
+
+// NOTE: `From<Box<T>>` is implemented for `Arc<T>`, therefore `NonEmptyArcStr`
+// can be constructed from `NonEmptyBoxedStr` without any additional implementations
+
+impl From<&NonEmptyStr> for NonEmptyArcStr {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        non_empty.to_non_empty_arc_str()
+    }
+}
+
+// NOTE: `From<NonEmptyCowStr<'_>>` is implemented for `Arc<B>`, provided `Arc<B>` is both
+// `From<&B>` and `From<B::Owned>`, therefore `NonEmptyArcStr` has it as well
+
+impl From<NonEmptyArcStr> for NonEmptyString {
+    fn from(non_empty: NonEmptyArcStr) -> Self {
+        Self::from_non_empty_str(&non_empty)
+    }
+}
+
+impl NonEmptyStr {
+    /// Constructs [`Self`] from [`Arc<str>`], provided the reference-counted string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyArcStr`] if the reference-counted string is empty.
+    pub fn from_arc_str(arc: Arc<str>) -> Result<Arc<Self>, EmptyArcStr> {
+        if arc.is_empty() {
+            return Err(EmptyArcStr::new(arc));
+        }
+
+        // SAFETY: the reference-counted string is non-empty at this point
+        Ok(unsafe { Self::from_arc_str_unchecked(arc) })
+    }
+
+    /// Constructs [`Self`] from [`Arc<str>`] without checking
+    /// if the reference-counted string is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the reference-counted string is non-empty.
+    #[must_use]
+    pub unsafe fn from_arc_str_unchecked(arc: Arc<str>) -> Arc<Self> {
+        // SAFETY: the caller must ensure that the reference-counted string is non-empty
+        // moreover, `Self` is `repr(transparent)`, so it is safe to transmute
+        // finally, `Arc` is created from the raw pointer existing within this function only
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const Self) }
+    }
+
+    /// Converts [`Self`] into [`Arc<str>`].
+    #[must_use]
+    pub fn into_arc_str(self: Arc<Self>) -> Arc<str> {
+        // SAFETY: `Self` is `repr(transparent)`, so it is safe to transmute
+        // moreover, `Arc` is created from the raw pointer existing within this function only
+        unsafe { Arc::from_raw(Arc::into_raw(self) as *const str) }
+    }
+
+    /// Converts [`Self`] to [`NonEmptyArcStr`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_arc_str(&self) -> NonEmptyArcStr {
+        // SAFETY: the string is non-empty by construction, so is the resulting one
+        unsafe { Self::from_arc_str_unchecked(self.as_str().into()) }
+    }
+}
+
+impl NonEmptyString {
+    /// Converts [`Self`] into [`NonEmptyArcStr`].
+    #[must_use]
+    pub fn into_non_empty_arc_str(self) -> NonEmptyArcStr {
+        // SAFETY: the string is non-empty by construction, so is the resulting one
+        unsafe { NonEmptyStr::from_arc_str_unchecked(self.into_string().into()) }
+    }
+}
+
+// NOTE: `FromNonEmptyIterator<T>` can only be implemented for `NonEmptyArcStr`
+// when `T` is local due to orphan rules
+
+impl<'s> FromNonEmptyIterator<&'s NonEmptyStr> for NonEmptyArcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = &'s NonEmptyStr>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_arc_str()
+    }
+}
+
+impl FromNonEmptyIterator<NonEmptyString> for NonEmptyArcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = NonEmptyString>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_arc_str()
+    }
+}
+
+impl FromNonEmptyIterator<NonEmptyBoxedStr> for NonEmptyArcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = NonEmptyBoxedStr>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_arc_str()
+    }
+}
@@ -0,0 +1,513 @@
+//! Non-empty fixed-capacity inline strings.
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    str::{self, FromStr},
+};
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{
+    internal::{Bytes, attempt, map_error},
+    str::{EmptyStr, NonEmptyStr},
+};
+
+/// The error message used when the capacity is insufficient.
+pub const CAPACITY_EXCEEDED: &str = "insufficient capacity";
+
+/// Represents errors returned when the capacity of [`NonEmptyArrayString`] is insufficient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("{CAPACITY_EXCEEDED}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::array_string::capacity),
+        help("make sure the string fits into the capacity")
+    )
+)]
+pub struct CapacityError;
+
+/// Represents errors returned when the received strings are either empty
+/// or do not fit into [`NonEmptyArrayString`].
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(transparent)
+)]
+pub enum MaybeEmptyCapacityError {
+    /// The received string is empty.
+    Empty(#[from] EmptyStr),
+    /// The received string is non-empty, but does not fit into the capacity.
+    Capacity(#[from] CapacityError),
+}
+
+/// Represents non-empty strings stored inline, with capacity of `N` bytes.
+///
+/// The capacity is required to be non-zero, otherwise the compilation will fail.
+///
+/// With the `schemars` feature, the JSON Schema of this type has `N` as its `maxLength`.
+/// Since JSON Schema measures length in characters rather than bytes, the schema
+/// accepts some strings that do not fit into this type.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+///
+/// use non_empty_str::{NonEmptyArrayString, non_empty_str};
+///
+/// let mut string: NonEmptyArrayString<16> = non_empty_str!("nekit").try_into().unwrap();
+///
+/// string.push_str("dev").unwrap();
+///
+/// write!(string, "!").unwrap();
+///
+/// assert_eq!(string.as_str(), "nekitdev!");
+/// ```
+#[derive(Clone, Copy)]
+pub struct NonEmptyArrayString<const N: usize> {
+    len: Size,
+    bytes: [u8; N],
+}
+
+impl<const N: usize> fmt::Debug for NonEmptyArrayString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl<const N: usize> fmt::Display for NonEmptyArrayString<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl<const N: usize> fmt::Write for NonEmptyArrayString<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, character: char) -> fmt::Result {
+        self.push(character).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> PartialEq for NonEmptyArrayString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for NonEmptyArrayString<N> {}
+
+impl<const N: usize> PartialOrd for NonEmptyArrayString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for NonEmptyArrayString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for NonEmptyArrayString<N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_non_empty_str().hash(hasher);
+    }
+}
+
+impl<const N: usize> FromStr for NonEmptyArrayString<N> {
+    type Err = MaybeEmptyCapacityError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(string)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for NonEmptyArrayString<N> {
+    type Error = MaybeEmptyCapacityError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(string)
+    }
+}
+
+impl<const N: usize> TryFrom<&NonEmptyStr> for NonEmptyArrayString<N> {
+    type Error = CapacityError;
+
+    fn try_from(non_empty: &NonEmptyStr) -> Result<Self, Self::Error> {
+        Self::from_non_empty_str(non_empty)
+    }
+}
+
+impl<const N: usize> Borrow<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl<const N: usize> BorrowMut<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn borrow_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl<const N: usize> Borrow<str> for NonEmptyArrayString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> BorrowMut<str> for NonEmptyArrayString<N> {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<Self> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<const N: usize> AsMut<Self> for NonEmptyArrayString<N> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<const N: usize> AsRef<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl<const N: usize> AsMut<NonEmptyStr> for NonEmptyArrayString<N> {
+    fn as_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsMut<str> for NonEmptyArrayString<N> {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<Bytes> for NonEmptyArrayString<N> {
+    fn as_ref(&self) -> &Bytes {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Deref for NonEmptyArrayString<N> {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl<const N: usize> DerefMut for NonEmptyArrayString<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl<const N: usize> NonEmptyArrayString<N> {
+    const CAPACITY: Size = Size::new(N).expect("expected non-zero capacity");
+
+    /// Constructs [`Self`] from [`str`], provided the string is non-empty and fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyCapacityError`] if the string is empty or does not fit.
+    pub const fn try_from_str(string: &str) -> Result<Self, MaybeEmptyCapacityError> {
+        let non_empty = attempt!(
+            map_error!(NonEmptyStr::try_from_str(string) => MaybeEmptyCapacityError::Empty)
+        );
+
+        map_error!(Self::from_non_empty_str(non_empty) => MaybeEmptyCapacityError::Capacity)
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`] via copying, provided the string fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the string does not fit.
+    pub const fn from_non_empty_str(non_empty: &NonEmptyStr) -> Result<Self, CapacityError> {
+        let len = non_empty.len();
+
+        if len.get() > Self::CAPACITY.get() {
+            return Err(CapacityError);
+        }
+
+        let mut bytes = [0; N];
+
+        let (target, _) = bytes.split_at_mut(len.get());
+
+        target.copy_from_slice(non_empty.as_bytes());
+
+        Ok(Self { len, bytes })
+    }
+
+    /// Constructs [`Self`] containing single provided character, provided it fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the character does not fit.
+    pub const fn single(character: char) -> Result<Self, CapacityError> {
+        let mut buffer = [0; 4];
+
+        let string = character.encode_utf8(&mut buffer);
+
+        // SAFETY: encoding any character results in non-empty string
+        Self::from_non_empty_str(unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.len
+    }
+
+    /// Returns the capacity of the string in bytes as [`Size`], which is always `N`.
+    #[must_use]
+    pub const fn capacity(&self) -> Size {
+        Self::CAPACITY
+    }
+
+    /// Returns the remaining capacity of the string in bytes.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY.get() - self.len.get()
+    }
+
+    /// Checks if the string is at full capacity.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len.get() == Self::CAPACITY.get()
+    }
+
+    /// Returns the underlying bytes of the string.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        let (bytes, _) = self.bytes.split_at(self.len.get());
+
+        bytes
+    }
+
+    /// Extracts the string slice containing the entire string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: the bytes up to the length are valid UTF-8 by construction
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns the mutable string slice containing the entire string.
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        let (bytes, _) = self.bytes.split_at_mut(self.len.get());
+
+        // SAFETY: the bytes up to the length are valid UTF-8 by construction
+        unsafe { str::from_utf8_unchecked_mut(bytes) }
+    }
+
+    /// Returns contained string reference as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_str_unchecked(self.as_str()) }
+    }
+
+    /// Returns contained mutable string reference as [`NonEmptyStr`].
+    pub const fn as_non_empty_mut_str(&mut self) -> &mut NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_mut_str_unchecked(self.as_mut_str()) }
+    }
+
+    /// Appends the given [`char`] to the end of this string, provided it fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the character does not fit.
+    pub fn push(&mut self, character: char) -> Result<(), CapacityError> {
+        self.push_str(character.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends the given [`str`] onto the end of this string, provided it fits.
+    ///
+    /// Nothing is appended in case of errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the string does not fit.
+    pub fn push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        let len = self.len.get();
+
+        if string.len() > self.remaining_capacity() {
+            return Err(CapacityError);
+        }
+
+        let new = len + string.len();
+
+        self.bytes[len..new].copy_from_slice(string.as_bytes());
+
+        // SAFETY: appending to non-empty string can not make the string empty
+        self.len = unsafe { Size::new_unchecked(new) };
+
+        Ok(())
+    }
+
+    /// Checks whether the string is almost empty, meaning it only contains one character.
+    #[must_use]
+    pub fn next_empty(&self) -> bool {
+        self.len().get() - self.first_char().len_utf8() == 0
+    }
+
+    /// The negated version of [`next_empty`].
+    ///
+    /// [`next_empty`]: Self::next_empty
+    #[must_use]
+    pub fn next_non_empty(&self) -> bool {
+        !self.next_empty()
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or [`None`] if the string would become empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let (index, character) = self.as_str().char_indices().next_back()?;
+
+        // NOTE: the last character starting at zero means that the string would become empty
+        let new = Size::new(index)?;
+
+        self.len = new;
+
+        Some(character)
+    }
+
+    /// Shortens this string to the specified non-zero length.
+    ///
+    /// Does nothing if `new` is greater than or equal to the string's [`len`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new` does not lie on character boundary.
+    ///
+    /// [`len`]: Self::len
+    pub fn truncate(&mut self, new: Size) {
+        if new >= self.len {
+            return;
+        }
+
+        assert!(
+            self.as_str().is_char_boundary(new.get()),
+            "expected new length to lie on character boundary"
+        );
+
+        self.len = new;
+    }
+
+    /// Inserts the given character at the specified index,
+    /// shifting all bytes after it to the right, provided it fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the character does not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn insert(&mut self, index: usize, character: char) -> Result<(), CapacityError> {
+        self.insert_str(index, character.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Inserts the given string at the specified index,
+    /// shifting all bytes after it to the right, provided it fits.
+    ///
+    /// Nothing is inserted in case of errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the string does not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn insert_str(&mut self, index: usize, string: &str) -> Result<(), CapacityError> {
+        assert!(
+            self.as_str().is_char_boundary(index),
+            "expected index to lie on character boundary"
+        );
+
+        if string.len() > self.remaining_capacity() {
+            return Err(CapacityError);
+        }
+
+        let len = self.len.get();
+        let shift = string.len();
+
+        self.bytes.copy_within(index..len, index + shift);
+        self.bytes[index..index + shift].copy_from_slice(string.as_bytes());
+
+        // SAFETY: inserting into non-empty string can not make the string empty
+        self.len = unsafe { Size::new_unchecked(len + shift) };
+
+        Ok(())
+    }
+
+    /// Removes and returns the character at the given index within the string,
+    /// shifting all bytes after it to the left.
+    ///
+    /// Returns [`None`] if the string would become empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn remove(&mut self, index: usize) -> Option<char> {
+        // NOTE: the index is checked first, so that invalid indices panic even if
+        // the string would become empty
+        let character = self.as_str()[index..]
+            .chars()
+            .next()
+            .expect("expected index to be in bounds");
+
+        if self.next_empty() {
+            return None;
+        }
+
+        let len = self.len.get();
+        let next = index + character.len_utf8();
+
+        self.bytes.copy_within(next..len, index);
+
+        // SAFETY: the string has more than one character at this point
+        self.len = unsafe { Size::new_unchecked(len - character.len_utf8()) };
+
+        Some(character)
+    }
+}
@@ -0,0 +1,711 @@
+//! Non-empty ASCII strings.
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+use core::{
+    fmt,
+    ops::{Deref, Index},
+    ptr,
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::{ops::DerefMut, str::FromStr};
+
+use non_empty_slice::NonEmptyBytes;
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{
+    internal::{Byte, Bytes, attempt, map_error},
+    str::{EmptyStr, NonEmptyStr},
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::string::{EmptyString, NonEmptyString};
+
+/// The error message used when the string contains non-ASCII characters.
+pub const NON_ASCII: &str = "the string contains non-ASCII characters";
+
+/// Represents errors returned when received strings contain non-ASCII characters.
+#[derive(Debug, Error)]
+#[error("{NON_ASCII}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::ascii),
+        help("make sure the string consists of ASCII characters only")
+    )
+)]
+pub struct NonAsciiStr;
+
+/// Represents errors returned when received strings are either empty or non-ASCII.
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(transparent)
+)]
+pub enum MaybeEmptyAsciiError {
+    /// The received string is empty.
+    Empty(#[from] EmptyStr),
+    /// The received string is non-empty, but contains non-ASCII characters.
+    NonAscii(#[from] NonAsciiStr),
+}
+
+/// The error message used when the owned string contains non-ASCII characters.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub const NON_ASCII_STRING: &str = "the string contains non-ASCII characters";
+
+/// Similar to [`NonAsciiStr`], but holds the non-ASCII string provided.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error("{NON_ASCII_STRING}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::ascii::string),
+        help("make sure the string consists of ASCII characters only")
+    )
+)]
+pub struct NonAsciiString {
+    string: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonAsciiString {
+    // NOTE: this is private to prevent creating this error with ASCII strings
+    pub(crate) const fn new(string: NonEmptyString) -> Self {
+        Self { string }
+    }
+
+    /// Returns the contained non-ASCII string.
+    #[must_use]
+    pub fn get(self) -> NonEmptyString {
+        self.string
+    }
+}
+
+/// Similar to [`MaybeEmptyAsciiError`], but holds the strings provided.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(transparent)
+)]
+pub enum FromMaybeEmptyAsciiError {
+    /// The received string is empty.
+    Empty(#[from] EmptyString),
+    /// The received string is non-empty, but contains non-ASCII characters.
+    NonAscii(#[from] NonAsciiString),
+}
+
+/// Represents non-empty [`str`] values that consist of ASCII characters only.
+///
+/// Since every character is exactly one byte, indexing by byte position is `O(1)`
+/// and never needs to check for character boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::const_non_empty_ascii_str;
+///
+/// let method = const_non_empty_ascii_str!("GET");
+///
+/// assert_eq!(method[0], b'G');
+/// assert_eq!(method.char_at(2), Some('T'));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyAsciiStr {
+    inner: NonEmptyStr,
+}
+
+impl fmt::Display for NonEmptyAsciiStr {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl<'s> TryFrom<&'s str> for &'s NonEmptyAsciiStr {
+    type Error = MaybeEmptyAsciiError;
+
+    fn try_from(string: &'s str) -> Result<Self, Self::Error> {
+        NonEmptyAsciiStr::try_from_str(string)
+    }
+}
+
+impl<'s> TryFrom<&'s NonEmptyStr> for &'s NonEmptyAsciiStr {
+    type Error = NonAsciiStr;
+
+    fn try_from(non_empty: &'s NonEmptyStr) -> Result<Self, Self::Error> {
+        NonEmptyAsciiStr::try_from_non_empty_str(non_empty)
+    }
+}
+
+impl<'s> From<&'s NonEmptyAsciiStr> for &'s NonEmptyStr {
+    fn from(ascii: &'s NonEmptyAsciiStr) -> Self {
+        ascii.as_non_empty_str()
+    }
+}
+
+impl<'s> From<&'s NonEmptyAsciiStr> for &'s str {
+    fn from(ascii: &'s NonEmptyAsciiStr) -> Self {
+        ascii.as_str()
+    }
+}
+
+impl AsRef<Self> for NonEmptyAsciiStr {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonEmptyAsciiStr {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyAsciiStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<NonEmptyBytes> for NonEmptyAsciiStr {
+    fn as_ref(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl AsRef<Bytes> for NonEmptyAsciiStr {
+    fn as_ref(&self) -> &Bytes {
+        self.as_bytes()
+    }
+}
+
+impl Index<usize> for NonEmptyAsciiStr {
+    type Output = Byte;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_bytes()[index]
+    }
+}
+
+impl Deref for NonEmptyAsciiStr {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl NonEmptyAsciiStr {
+    /// Constructs [`Self`] from anything that can be converted to string,
+    /// provided it is non-empty and ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyAsciiError`] if the string is empty or non-ASCII.
+    pub fn try_new<S: AsRef<str> + ?Sized>(string: &S) -> Result<&Self, MaybeEmptyAsciiError> {
+        Self::try_from_str(string.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<str> + ?Sized>(string: &S) -> Option<&Self> {
+        Self::from_str(string.as_ref())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to string, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and ASCII.
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<str> + ?Sized>(string: &S) -> &Self {
+        // SAFETY: the caller must ensure that the string is non-empty and ASCII
+        unsafe { Self::from_str_unchecked(string.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`str`], provided the string is non-empty and ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyAsciiError`] if the string is empty or non-ASCII.
+    pub const fn try_from_str(string: &str) -> Result<&Self, MaybeEmptyAsciiError> {
+        let non_empty =
+            attempt!(map_error!(NonEmptyStr::try_from_str(string) => MaybeEmptyAsciiError::Empty));
+
+        map_error!(Self::try_from_non_empty_str(non_empty) => MaybeEmptyAsciiError::NonAscii)
+    }
+
+    /// Similar to [`try_from_str`], but the error is discarded.
+    ///
+    /// [`try_from_str`]: Self::try_from_str
+    #[must_use]
+    pub const fn from_str(string: &str) -> Option<&Self> {
+        match NonEmptyStr::from_str(string) {
+            Some(non_empty) => Self::from_non_empty_str(non_empty),
+            None => None,
+        }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], provided the string is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiStr`] if the string contains non-ASCII characters.
+    pub const fn try_from_non_empty_str(non_empty: &NonEmptyStr) -> Result<&Self, NonAsciiStr> {
+        if !non_empty.is_ascii() {
+            return Err(NonAsciiStr);
+        }
+
+        // SAFETY: the string is non-empty and ASCII at this point
+        Ok(unsafe { Self::from_str_unchecked(non_empty.as_str()) })
+    }
+
+    /// Similar to [`try_from_non_empty_str`], but the error is discarded.
+    ///
+    /// [`try_from_non_empty_str`]: Self::try_from_non_empty_str
+    #[must_use]
+    pub const fn from_non_empty_str(non_empty: &NonEmptyStr) -> Option<&Self> {
+        if !non_empty.is_ascii() {
+            return None;
+        }
+
+        // SAFETY: the string is non-empty and ASCII at this point
+        Some(unsafe { Self::from_str_unchecked(non_empty.as_str()) })
+    }
+
+    /// Constructs [`Self`] from [`str`], without checking if the string is non-empty and ASCII.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and ASCII.
+    #[must_use]
+    pub const unsafe fn from_str_unchecked(string: &str) -> &Self {
+        debug_assert!(string.is_ascii());
+
+        // SAFETY: the caller must ensure that the string is non-empty and ASCII
+        // `Self` is `repr(transparent)` over `NonEmptyStr`, which is `repr(transparent)`
+        // over `str`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(string) as *const Self) }
+    }
+
+    /// Constructs [`Self`] from mutable [`str`], without checking if the string
+    /// is non-empty and ASCII.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and ASCII.
+    pub const unsafe fn from_mut_str_unchecked(string: &mut str) -> &mut Self {
+        debug_assert!(string.is_ascii());
+
+        // SAFETY: the caller must ensure that the string is non-empty and ASCII
+        // `Self` is `repr(transparent)` over `NonEmptyStr`, which is `repr(transparent)`
+        // over `str`, so it is safe to transmute
+        unsafe { &mut *(ptr::from_mut(string) as *mut Self) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        &self.inner
+    }
+
+    /// Returns the contained string as bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        self.inner.as_bytes()
+    }
+
+    /// Returns the contained string as [`NonEmptyBytes`].
+    #[must_use]
+    pub const fn as_non_empty_bytes(&self) -> &NonEmptyBytes {
+        self.inner.as_non_empty_bytes()
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string as [`Size`].
+    ///
+    /// Since the string is ASCII, this is both the number of bytes and characters.
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.inner.len()
+    }
+
+    /// Returns the byte at the given position, or [`None`] if it is out of bounds.
+    #[must_use]
+    pub const fn byte_at(&self, index: usize) -> Option<Byte> {
+        if index < self.len().get() {
+            Some(self.as_bytes()[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the character at the given position, or [`None`] if it is out of bounds.
+    ///
+    /// Unlike [`NonEmptyStr::char_at`], this never needs to check for character boundaries.
+    #[must_use]
+    pub const fn char_at(&self, index: usize) -> Option<char> {
+        match self.byte_at(index) {
+            Some(byte) => Some(byte as char),
+            None => None,
+        }
+    }
+
+    /// Returns the first character of this string.
+    #[must_use]
+    pub const fn first_char(&self) -> char {
+        *self.as_non_empty_bytes().first() as char
+    }
+
+    /// Returns the last character of this string.
+    #[must_use]
+    pub const fn last_char(&self) -> char {
+        *self.as_non_empty_bytes().last() as char
+    }
+
+    /// Converts the string to its ASCII uppercase equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.inner.make_ascii_uppercase();
+    }
+
+    /// Converts the string to its ASCII lowercase equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.inner.make_ascii_lowercase();
+    }
+}
+
+impl NonEmptyStr {
+    /// Converts the string to [`NonEmptyAsciiStr`], provided it is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiStr`] if the string contains non-ASCII characters.
+    pub const fn try_as_non_empty_ascii_str(&self) -> Result<&NonEmptyAsciiStr, NonAsciiStr> {
+        NonEmptyAsciiStr::try_from_non_empty_str(self)
+    }
+}
+
+/// Represents non-empty [`String`] values that consist of ASCII characters only.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyAsciiString {
+    inner: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl fmt::Display for NonEmptyAsciiString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl FromStr for NonEmptyAsciiString {
+    type Err = MaybeEmptyAsciiError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        NonEmptyAsciiStr::try_from_str(string).map(Self::from_non_empty_ascii_str)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl ToOwned for NonEmptyAsciiStr {
+    type Owned = NonEmptyAsciiString;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_empty_ascii_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<NonEmptyAsciiStr> for NonEmptyAsciiString {
+    fn borrow(&self) -> &NonEmptyAsciiStr {
+        self.as_non_empty_ascii_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<NonEmptyStr> for NonEmptyAsciiString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<str> for NonEmptyAsciiString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<String> for NonEmptyAsciiString {
+    type Error = FromMaybeEmptyAsciiError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<NonEmptyString> for NonEmptyAsciiString {
+    type Error = NonAsciiString;
+
+    fn try_from(non_empty: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<&str> for NonEmptyAsciiString {
+    type Error = MaybeEmptyAsciiError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<&NonEmptyAsciiStr> for NonEmptyAsciiString {
+    fn from(ascii: &NonEmptyAsciiStr) -> Self {
+        Self::from_non_empty_ascii_str(ascii)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<NonEmptyAsciiString> for NonEmptyString {
+    fn from(ascii: NonEmptyAsciiString) -> Self {
+        ascii.into_non_empty_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<NonEmptyAsciiString> for String {
+    fn from(ascii: NonEmptyAsciiString) -> Self {
+        ascii.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<Self> for NonEmptyAsciiString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<NonEmptyAsciiStr> for NonEmptyAsciiString {
+    fn as_ref(&self) -> &NonEmptyAsciiStr {
+        self.as_non_empty_ascii_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<NonEmptyStr> for NonEmptyAsciiString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<str> for NonEmptyAsciiString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<Bytes> for NonEmptyAsciiString {
+    fn as_ref(&self) -> &Bytes {
+        self.as_bytes()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Deref for NonEmptyAsciiString {
+    type Target = NonEmptyAsciiStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_ascii_str()
+    }
+}
+
+// NOTE: mutable access to `NonEmptyAsciiStr` is fine, since it only allows operations
+// that keep the string ASCII
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl DerefMut for NonEmptyAsciiString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_ascii_mut_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonEmptyAsciiString {
+    /// Constructs [`Self`], provided that the [`String`] is non-empty and ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromMaybeEmptyAsciiError`] if the string is empty or non-ASCII.
+    pub fn new(string: String) -> Result<Self, FromMaybeEmptyAsciiError> {
+        let non_empty = NonEmptyString::new(string)?;
+
+        let ascii = Self::from_non_empty_string(non_empty)?;
+
+        Ok(ascii)
+    }
+
+    /// Constructs [`Self`] without checking if the [`String`] is non-empty and ASCII.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and ASCII.
+    #[must_use]
+    pub const unsafe fn new_unchecked(string: String) -> Self {
+        // SAFETY: the caller must ensure that the string is non-empty
+        let inner = unsafe { NonEmptyString::new_unchecked(string) };
+
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyString`], provided it is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiString`] if the string contains non-ASCII characters.
+    pub fn from_non_empty_string(non_empty: NonEmptyString) -> Result<Self, NonAsciiString> {
+        if !non_empty.is_ascii() {
+            return Err(NonAsciiString::new(non_empty));
+        }
+
+        Ok(Self { inner: non_empty })
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyAsciiStr`] via cloning.
+    #[must_use]
+    pub fn from_non_empty_ascii_str(ascii: &NonEmptyAsciiStr) -> Self {
+        Self {
+            inner: ascii.to_non_empty_string(),
+        }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.inner.as_non_empty_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyAsciiStr`].
+    #[must_use]
+    pub const fn as_non_empty_ascii_str(&self) -> &NonEmptyAsciiStr {
+        // SAFETY: the string is non-empty and ASCII by construction
+        unsafe { NonEmptyAsciiStr::from_str_unchecked(self.as_str()) }
+    }
+
+    /// Returns the contained string as mutable [`NonEmptyAsciiStr`].
+    #[must_use]
+    pub const fn as_non_empty_ascii_mut_str(&mut self) -> &mut NonEmptyAsciiStr {
+        // SAFETY: the string is non-empty and ASCII by construction
+        unsafe { NonEmptyAsciiStr::from_mut_str_unchecked(self.inner.as_mut_str()) }
+    }
+
+    /// Returns the contained [`NonEmptyString`].
+    #[must_use]
+    pub const fn as_non_empty_string(&self) -> &NonEmptyString {
+        &self.inner
+    }
+
+    /// Returns the contained [`String`].
+    #[must_use]
+    pub const fn as_string(&self) -> &String {
+        self.inner.as_string()
+    }
+
+    /// Converts [`Self`] into the contained [`NonEmptyString`].
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.inner
+    }
+
+    /// Converts [`Self`] into the contained [`String`].
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner.into_string()
+    }
+
+    /// Appends the given [`NonEmptyAsciiStr`] onto the end of this string.
+    pub fn push_str(&mut self, ascii: &NonEmptyAsciiStr) {
+        self.inner.push_str(ascii.as_str());
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonEmptyAsciiStr {
+    /// Converts [`Self`] into [`NonEmptyAsciiString`].
+    #[must_use]
+    pub fn to_non_empty_ascii_string(&self) -> NonEmptyAsciiString {
+        NonEmptyAsciiString::from_non_empty_ascii_str(self)
+    }
+
+    /// Returns the ASCII uppercase equivalent of this string as [`NonEmptyAsciiString`].
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> NonEmptyAsciiString {
+        let mut ascii = self.to_non_empty_ascii_string();
+
+        ascii.make_ascii_uppercase();
+
+        ascii
+    }
+
+    /// Returns the ASCII lowercase equivalent of this string as [`NonEmptyAsciiString`].
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> NonEmptyAsciiString {
+        let mut ascii = self.to_non_empty_ascii_string();
+
+        ascii.make_ascii_lowercase();
+
+        ascii
+    }
+}
@@ -0,0 +1,559 @@
+//! Non-blank strings, that is, strings containing at least one non-whitespace character.
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+use core::{fmt, ops::Deref, ptr};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::str::FromStr;
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::str::NonEmptyStr;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::string::NonEmptyString;
+
+/// The error message used when the string is blank.
+pub const BLANK_STR: &str = "the string is blank";
+
+/// Represents errors returned when received strings are blank,
+/// meaning they are empty or consist of whitespace only.
+#[derive(Debug, Error)]
+#[error("{BLANK_STR}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::blank),
+        help("make sure the string contains non-whitespace characters")
+    )
+)]
+pub struct BlankStr;
+
+/// The error message used when the owned string is blank.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub const BLANK_STRING: &str = "the string is blank";
+
+/// Similar to [`BlankStr`], but holds the blank string provided.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error("{BLANK_STRING}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::blank::string),
+        help("make sure the string contains non-whitespace characters")
+    )
+)]
+pub struct BlankString {
+    string: String,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl BlankString {
+    // NOTE: this is private to prevent creating this error with non-blank strings
+    pub(crate) const fn new(string: String) -> Self {
+        Self { string }
+    }
+
+    /// Returns the contained blank string.
+    #[must_use]
+    pub fn get(self) -> String {
+        self.string
+    }
+}
+
+// NOTE: empty strings are blank, as they contain no non-whitespace characters
+pub(crate) fn is_blank(string: &str) -> bool {
+    string.chars().all(char::is_whitespace)
+}
+
+/// Represents non-blank [`str`] values, that is, strings that contain
+/// at least one non-whitespace character.
+///
+/// Every non-blank string is non-empty, therefore [`NonBlankStr`] dereferences to [`NonEmptyStr`].
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::NonBlankStr;
+///
+/// let name = NonBlankStr::from_str("  nekit  ").unwrap();
+///
+/// assert_eq!(name.trim().as_str(), "nekit");
+///
+/// assert!(NonBlankStr::from_str(" \t\n").is_none());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonBlankStr {
+    inner: NonEmptyStr,
+}
+
+impl fmt::Display for NonBlankStr {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl<'s> TryFrom<&'s str> for &'s NonBlankStr {
+    type Error = BlankStr;
+
+    fn try_from(string: &'s str) -> Result<Self, Self::Error> {
+        NonBlankStr::try_from_str(string)
+    }
+}
+
+impl<'s> TryFrom<&'s NonEmptyStr> for &'s NonBlankStr {
+    type Error = BlankStr;
+
+    fn try_from(non_empty: &'s NonEmptyStr) -> Result<Self, Self::Error> {
+        NonBlankStr::try_from_non_empty_str(non_empty)
+    }
+}
+
+impl<'s> From<&'s NonBlankStr> for &'s NonEmptyStr {
+    fn from(non_blank: &'s NonBlankStr) -> Self {
+        non_blank.as_non_empty_str()
+    }
+}
+
+impl<'s> From<&'s NonBlankStr> for &'s str {
+    fn from(non_blank: &'s NonBlankStr) -> Self {
+        non_blank.as_str()
+    }
+}
+
+impl AsRef<Self> for NonBlankStr {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonBlankStr {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl AsRef<str> for NonBlankStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Deref for NonBlankStr {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl NonBlankStr {
+    /// Constructs [`Self`] from anything that can be converted to string, provided it is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankStr`] if the string is blank.
+    pub fn try_new<S: AsRef<str> + ?Sized>(string: &S) -> Result<&Self, BlankStr> {
+        Self::try_from_str(string.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<str> + ?Sized>(string: &S) -> Option<&Self> {
+        Self::from_str(string.as_ref())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to string, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-blank.
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<str> + ?Sized>(string: &S) -> &Self {
+        // SAFETY: the caller must ensure that the string is non-blank
+        unsafe { Self::from_str_unchecked(string.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`str`], provided the string is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankStr`] if the string is blank.
+    pub fn try_from_str(string: &str) -> Result<&Self, BlankStr> {
+        if is_blank(string) {
+            return Err(BlankStr);
+        }
+
+        // SAFETY: the string is non-blank at this point
+        Ok(unsafe { Self::from_str_unchecked(string) })
+    }
+
+    /// Similar to [`try_from_str`], but the error is discarded.
+    ///
+    /// [`try_from_str`]: Self::try_from_str
+    // NOTE: this can not be `const` since checking for whitespace requires iterating
+    // over characters; `FromStr` does not apply, as `NonBlankStr` is unsized
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn from_str(string: &str) -> Option<&Self> {
+        Self::try_from_str(string).ok()
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], provided the string is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankStr`] if the string is blank.
+    pub fn try_from_non_empty_str(non_empty: &NonEmptyStr) -> Result<&Self, BlankStr> {
+        Self::try_from_str(non_empty.as_str())
+    }
+
+    /// Constructs [`Self`] from [`str`], without checking if the string is blank.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-blank.
+    #[must_use]
+    pub const unsafe fn from_str_unchecked(string: &str) -> &Self {
+        // SAFETY: the caller must ensure that the string is non-blank, therefore non-empty
+        // `Self` is `repr(transparent)` over `NonEmptyStr`, which is `repr(transparent)`
+        // over `str`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(string) as *const Self) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        &self.inner
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.inner.len()
+    }
+
+    /// Returns new string with leading and trailing whitespace removed.
+    ///
+    /// The returned string is guaranteed to be non-blank, and therefore non-empty.
+    #[must_use]
+    pub fn trim(&self) -> &Self {
+        // SAFETY: trimming whitespace can not remove non-whitespace characters
+        unsafe { Self::from_str_unchecked(self.as_str().trim()) }
+    }
+
+    /// Returns new string with leading whitespace removed.
+    ///
+    /// The returned string is guaranteed to be non-blank, and therefore non-empty.
+    #[must_use]
+    pub fn trim_start(&self) -> &Self {
+        // SAFETY: trimming whitespace can not remove non-whitespace characters
+        unsafe { Self::from_str_unchecked(self.as_str().trim_start()) }
+    }
+
+    /// Returns new string with trailing whitespace removed.
+    ///
+    /// The returned string is guaranteed to be non-blank, and therefore non-empty.
+    #[must_use]
+    pub fn trim_end(&self) -> &Self {
+        // SAFETY: trimming whitespace can not remove non-whitespace characters
+        unsafe { Self::from_str_unchecked(self.as_str().trim_end()) }
+    }
+}
+
+impl NonEmptyStr {
+    /// Checks whether the string is blank, meaning it consists of whitespace only.
+    #[must_use]
+    pub fn is_blank(&self) -> bool {
+        is_blank(self.as_str())
+    }
+
+    /// Converts the string to [`NonBlankStr`], provided it is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankStr`] if the string is blank.
+    pub fn try_as_non_blank_str(&self) -> Result<&NonBlankStr, BlankStr> {
+        NonBlankStr::try_from_non_empty_str(self)
+    }
+}
+
+/// Represents non-blank [`String`] values, that is, strings that contain
+/// at least one non-whitespace character.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonBlankString {
+    inner: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl fmt::Display for NonBlankString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl FromStr for NonBlankString {
+    type Err = BlankStr;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        NonBlankStr::try_from_str(string).map(Self::from_non_blank_str)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl ToOwned for NonBlankStr {
+    type Owned = NonBlankString;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_blank_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<NonBlankStr> for NonBlankString {
+    fn borrow(&self) -> &NonBlankStr {
+        self.as_non_blank_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<NonEmptyStr> for NonBlankString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Borrow<str> for NonBlankString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<String> for NonBlankString {
+    type Error = BlankString;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<NonEmptyString> for NonBlankString {
+    type Error = BlankString;
+
+    fn try_from(non_empty: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl TryFrom<&str> for NonBlankString {
+    type Error = BlankStr;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<&NonBlankStr> for NonBlankString {
+    fn from(non_blank: &NonBlankStr) -> Self {
+        Self::from_non_blank_str(non_blank)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<NonBlankString> for NonEmptyString {
+    fn from(non_blank: NonBlankString) -> Self {
+        non_blank.into_non_empty_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<NonBlankString> for String {
+    fn from(non_blank: NonBlankString) -> Self {
+        non_blank.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<Self> for NonBlankString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<NonBlankStr> for NonBlankString {
+    fn as_ref(&self) -> &NonBlankStr {
+        self.as_non_blank_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<NonEmptyStr> for NonBlankString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl AsRef<str> for NonBlankString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Deref for NonBlankString {
+    type Target = NonBlankStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_blank_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonBlankString {
+    /// Constructs [`Self`], provided that the [`String`] is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankString`] if the string is blank.
+    pub fn new(string: String) -> Result<Self, BlankString> {
+        if is_blank(&string) {
+            return Err(BlankString::new(string));
+        }
+
+        // SAFETY: the string is non-blank at this point
+        Ok(unsafe { Self::new_unchecked(string) })
+    }
+
+    /// Constructs [`Self`] without checking if the [`String`] is blank.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-blank.
+    #[must_use]
+    pub const unsafe fn new_unchecked(string: String) -> Self {
+        // SAFETY: the caller must ensure that the string is non-blank, therefore non-empty
+        let inner = unsafe { NonEmptyString::new_unchecked(string) };
+
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyString`], provided it is non-blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BlankString`] if the string is blank.
+    pub fn from_non_empty_string(non_empty: NonEmptyString) -> Result<Self, BlankString> {
+        Self::new(non_empty.into_string())
+    }
+
+    /// Constructs [`Self`] from [`NonBlankStr`] via cloning.
+    #[must_use]
+    pub fn from_non_blank_str(non_blank: &NonBlankStr) -> Self {
+        // SAFETY: the string is non-blank by construction
+        unsafe { Self::new_unchecked(String::from(non_blank.as_str())) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.inner.as_non_empty_str()
+    }
+
+    /// Returns the contained string as [`NonBlankStr`].
+    #[must_use]
+    pub const fn as_non_blank_str(&self) -> &NonBlankStr {
+        // SAFETY: the string is non-blank by construction
+        unsafe { NonBlankStr::from_str_unchecked(self.as_str()) }
+    }
+
+    /// Returns the contained [`NonEmptyString`].
+    #[must_use]
+    pub const fn as_non_empty_string(&self) -> &NonEmptyString {
+        &self.inner
+    }
+
+    /// Returns the contained [`String`].
+    #[must_use]
+    pub const fn as_string(&self) -> &String {
+        self.inner.as_string()
+    }
+
+    /// Converts [`Self`] into the contained [`NonEmptyString`].
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.inner
+    }
+
+    /// Converts [`Self`] into the contained [`String`].
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner.into_string()
+    }
+
+    /// Appends the given [`char`] to the end of this string.
+    pub fn push(&mut self, character: char) {
+        self.inner.push(character);
+    }
+
+    /// Appends the given [`str`] onto the end of this string.
+    pub fn push_str(&mut self, string: &str) {
+        self.inner.push_str(string);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonBlankStr {
+    /// Converts [`Self`] into [`NonBlankString`].
+    #[must_use]
+    pub fn to_non_blank_string(&self) -> NonBlankString {
+        NonBlankString::from_non_blank_str(self)
+    }
+}
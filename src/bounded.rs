@@ -0,0 +1,785 @@
+//! Non-empty strings with bounded length.
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::str::FromStr;
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::str::NonEmptyStr;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::string::NonEmptyString;
+
+/// Represents units in which the length of strings is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// The length is measured in bytes.
+    Bytes,
+    /// The length is measured in characters.
+    Chars,
+}
+
+impl Unit {
+    /// Returns the name of this unit.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Chars => "chars",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(formatter)
+    }
+}
+
+/// Represents ways of measuring the length of strings.
+pub trait Measure {
+    /// The unit the length is measured in.
+    const UNIT: Unit;
+
+    /// Measures the length of the given string.
+    fn measure(string: &str) -> usize;
+}
+
+/// Measures the length of strings in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Bytes;
+
+impl Measure for Bytes {
+    const UNIT: Unit = Unit::Bytes;
+
+    fn measure(string: &str) -> usize {
+        string.len()
+    }
+}
+
+/// Measures the length of strings in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Chars;
+
+impl Measure for Chars {
+    const UNIT: Unit = Unit::Chars;
+
+    fn measure(string: &str) -> usize {
+        string.chars().count()
+    }
+}
+
+/// Represents errors returned when the length of received strings is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
+pub enum BoundsError {
+    /// The string is shorter than the minimum length.
+    #[error("expected at least {min} {unit}, got {length}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_str::bounded::short),
+            help("make sure the string is long enough")
+        )
+    )]
+    TooShort {
+        /// The minimum length.
+        min: usize,
+        /// The actual length.
+        length: usize,
+        /// The unit of length.
+        unit: Unit,
+    },
+    /// The string is longer than the maximum length.
+    #[error("expected at most {max} {unit}, got {length}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_str::bounded::long),
+            help("make sure the string is short enough")
+        )
+    )]
+    TooLong {
+        /// The maximum length.
+        max: usize,
+        /// The actual length.
+        length: usize,
+        /// The unit of length.
+        unit: Unit,
+    },
+}
+
+impl BoundsError {
+    /// Returns the actual length of the string.
+    #[must_use]
+    pub const fn length(self) -> usize {
+        match self {
+            Self::TooShort { length, .. } | Self::TooLong { length, .. } => length,
+        }
+    }
+
+    /// Returns the unit of length.
+    #[must_use]
+    pub const fn unit(self) -> Unit {
+        match self {
+            Self::TooShort { unit, .. } | Self::TooLong { unit, .. } => unit,
+        }
+    }
+}
+
+/// Couples [`BoundsError`] with the [`String`] that is out of bounds.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error("{error}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::bounded::string),
+        help("make sure the string length is within bounds")
+    )
+)]
+pub struct FromBoundsError {
+    #[source]
+    #[cfg_attr(feature = "diagnostics", diagnostic_source)]
+    error: BoundsError,
+    string: String,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl FromBoundsError {
+    // NOTE: this is private to prevent creating this error with strings within bounds
+    pub(crate) const fn new(error: BoundsError, string: String) -> Self {
+        Self { error, string }
+    }
+
+    /// Returns the underlying bounds error.
+    #[must_use]
+    pub const fn error(&self) -> BoundsError {
+        self.error
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub fn get(self) -> String {
+        self.string
+    }
+
+    /// Recovers the underlying bounds error and the string.
+    #[must_use]
+    pub fn recover(self) -> (BoundsError, String) {
+        (self.error, self.string)
+    }
+}
+
+/// Represents [`str`] values with length in `MIN..=MAX`, measured according to `M`.
+///
+/// The minimum length is required to be non-zero and not greater than the maximum,
+/// otherwise constructing values, including via unchecked constructors, fails to compile.
+/// Therefore [`BoundedStr`] dereferences to [`NonEmptyStr`].
+///
+/// With the `schemars` feature, the JSON Schema of this type measures length in characters.
+/// When measuring in [`Bytes`], the schema only approximates the bounds: `minLength` is `MIN`
+/// divided by four (rounding up) and `maxLength` is `MAX`, so the schema accepts some strings
+/// that are out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::{BoundedStr, bounded::{BoundsError, Chars, Unit}};
+///
+/// type Name = BoundedStr<1, 5, Chars>;
+///
+/// let name = Name::try_from_str("привет").unwrap_err();
+///
+/// assert_eq!(name, BoundsError::TooLong { max: 5, length: 6, unit: Unit::Chars });
+///
+/// let short = Name::try_from_str("nekit").unwrap();
+///
+/// assert_eq!(short.as_str(), "nekit");
+/// ```
+///
+/// Failing compilation on zero minimum length:
+///
+/// ```compile_fail
+/// use non_empty_str::BoundedStr;
+///
+/// let never = BoundedStr::<0, 8>::from_str("nekit");
+/// ```
+///
+/// The same applies to unchecked construction:
+///
+/// ```compile_fail
+/// use non_empty_str::BoundedStr;
+///
+/// let never = unsafe { BoundedStr::<5, 3>::from_str_unchecked("nekit") };
+/// ```
+#[repr(transparent)]
+pub struct BoundedStr<const MIN: usize, const MAX: usize, M = Bytes> {
+    measure: PhantomData<fn() -> M>,
+    inner: NonEmptyStr,
+}
+
+impl<const MIN: usize, const MAX: usize, M> fmt::Debug for BoundedStr<MIN, MAX, M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> fmt::Display for BoundedStr<MIN, MAX, M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> PartialEq for BoundedStr<MIN, MAX, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_non_empty_str() == other.as_non_empty_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> Eq for BoundedStr<MIN, MAX, M> {}
+
+impl<const MIN: usize, const MAX: usize, M> PartialOrd for BoundedStr<MIN, MAX, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> Ord for BoundedStr<MIN, MAX, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_non_empty_str().cmp(other.as_non_empty_str())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> Hash for BoundedStr<MIN, MAX, M> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_non_empty_str().hash(hasher);
+    }
+}
+
+impl<'s, const MIN: usize, const MAX: usize, M: Measure> TryFrom<&'s str>
+    for &'s BoundedStr<MIN, MAX, M>
+{
+    type Error = BoundsError;
+
+    fn try_from(string: &'s str) -> Result<Self, Self::Error> {
+        BoundedStr::try_from_str(string)
+    }
+}
+
+impl<'s, const MIN: usize, const MAX: usize, M: Measure> TryFrom<&'s NonEmptyStr>
+    for &'s BoundedStr<MIN, MAX, M>
+{
+    type Error = BoundsError;
+
+    fn try_from(non_empty: &'s NonEmptyStr) -> Result<Self, Self::Error> {
+        BoundedStr::try_from_non_empty_str(non_empty)
+    }
+}
+
+impl<'s, const MIN: usize, const MAX: usize, M> From<&'s BoundedStr<MIN, MAX, M>>
+    for &'s NonEmptyStr
+{
+    fn from(bounded: &'s BoundedStr<MIN, MAX, M>) -> Self {
+        bounded.as_non_empty_str()
+    }
+}
+
+impl<'s, const MIN: usize, const MAX: usize, M> From<&'s BoundedStr<MIN, MAX, M>> for &'s str {
+    fn from(bounded: &'s BoundedStr<MIN, MAX, M>) -> Self {
+        bounded.as_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> AsRef<Self> for BoundedStr<MIN, MAX, M> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> AsRef<NonEmptyStr> for BoundedStr<MIN, MAX, M> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> AsRef<str> for BoundedStr<MIN, MAX, M> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> Deref for BoundedStr<MIN, MAX, M> {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M: Measure> BoundedStr<MIN, MAX, M> {
+    /// Checks whether the given string is within bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundsError`] if the string is out of bounds.
+    pub fn check(string: &str) -> Result<(), BoundsError> {
+        let () = Self::BOUNDS;
+
+        let length = M::measure(string);
+
+        // NOTE: emptiness is checked separately to uphold the non-empty invariant
+        // regardless of how `M` measures the length
+        if string.is_empty() || length < MIN {
+            return Err(BoundsError::TooShort {
+                min: MIN,
+                length,
+                unit: M::UNIT,
+            });
+        }
+
+        if length > MAX {
+            return Err(BoundsError::TooLong {
+                max: MAX,
+                length,
+                unit: M::UNIT,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to string, provided it is in bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundsError`] if the string is out of bounds.
+    pub fn try_new<S: AsRef<str> + ?Sized>(string: &S) -> Result<&Self, BoundsError> {
+        Self::try_from_str(string.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<str> + ?Sized>(string: &S) -> Option<&Self> {
+        Self::from_str(string.as_ref())
+    }
+
+    /// Constructs [`Self`] from [`str`], provided the string is in bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundsError`] if the string is out of bounds.
+    pub fn try_from_str(string: &str) -> Result<&Self, BoundsError> {
+        Self::check(string)?;
+
+        // SAFETY: the string is non-empty and in bounds at this point
+        Ok(unsafe { Self::from_str_unchecked(string) })
+    }
+
+    /// Similar to [`try_from_str`], but the error is discarded.
+    ///
+    /// [`try_from_str`]: Self::try_from_str
+    // NOTE: this can not be `const` since `Measure` is a trait; `FromStr` does not apply,
+    // as `BoundedStr` is unsized
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn from_str(string: &str) -> Option<&Self> {
+        Self::try_from_str(string).ok()
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], provided the string is in bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundsError`] if the string is out of bounds.
+    pub fn try_from_non_empty_str(non_empty: &NonEmptyStr) -> Result<&Self, BoundsError> {
+        Self::try_from_str(non_empty.as_str())
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> BoundedStr<MIN, MAX, M> {
+    /// Asserts that `0 < MIN <= MAX`; referenced by every constructor.
+    const BOUNDS: () = assert!(MIN > 0 && MIN <= MAX, "expected `0 < MIN <= MAX`");
+
+    /// Constructs [`Self`] from anything that can be converted to string, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and in bounds.
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<str> + ?Sized>(string: &S) -> &Self {
+        // SAFETY: the caller must ensure that the string is non-empty and in bounds
+        unsafe { Self::from_str_unchecked(string.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`str`], without checking if the string is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and in bounds.
+    #[must_use]
+    pub const unsafe fn from_str_unchecked(string: &str) -> &Self {
+        let () = Self::BOUNDS;
+
+        debug_assert!(!string.is_empty());
+
+        // SAFETY: the caller must ensure that the string is non-empty and in bounds
+        // `Self` is `repr(transparent)` over `NonEmptyStr`, which is `repr(transparent)`
+        // over `str`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(string) as *const Self) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        &self.inner
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.inner.len()
+    }
+}
+
+/// Represents [`String`] values with length in `MIN..=MAX`, measured according to `M`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[repr(transparent)]
+pub struct BoundedString<const MIN: usize, const MAX: usize, M = Bytes> {
+    measure: PhantomData<fn() -> M>,
+    inner: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Clone for BoundedString<MIN, MAX, M> {
+    fn clone(&self) -> Self {
+        Self {
+            measure: PhantomData,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> fmt::Debug for BoundedString<MIN, MAX, M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> fmt::Display for BoundedString<MIN, MAX, M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> PartialEq for BoundedString<MIN, MAX, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bounded_str() == other.as_bounded_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Eq for BoundedString<MIN, MAX, M> {}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> PartialOrd for BoundedString<MIN, MAX, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Ord for BoundedString<MIN, MAX, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bounded_str().cmp(other.as_bounded_str())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Hash for BoundedString<MIN, MAX, M> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_bounded_str().hash(hasher);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M: Measure> FromStr for BoundedString<MIN, MAX, M> {
+    type Err = BoundsError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        BoundedStr::try_from_str(string).map(Self::from_bounded_str)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> ToOwned for BoundedStr<MIN, MAX, M> {
+    type Owned = BoundedString<MIN, MAX, M>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_bounded_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Borrow<BoundedStr<MIN, MAX, M>>
+    for BoundedString<MIN, MAX, M>
+{
+    fn borrow(&self) -> &BoundedStr<MIN, MAX, M> {
+        self.as_bounded_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Borrow<NonEmptyStr> for BoundedString<MIN, MAX, M> {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Borrow<str> for BoundedString<MIN, MAX, M> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M: Measure> TryFrom<String>
+    for BoundedString<MIN, MAX, M>
+{
+    type Error = FromBoundsError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M: Measure> TryFrom<NonEmptyString>
+    for BoundedString<MIN, MAX, M>
+{
+    type Error = FromBoundsError;
+
+    fn try_from(non_empty: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M: Measure> TryFrom<&str> for BoundedString<MIN, MAX, M> {
+    type Error = BoundsError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> From<&BoundedStr<MIN, MAX, M>>
+    for BoundedString<MIN, MAX, M>
+{
+    fn from(bounded: &BoundedStr<MIN, MAX, M>) -> Self {
+        Self::from_bounded_str(bounded)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> From<BoundedString<MIN, MAX, M>> for NonEmptyString {
+    fn from(bounded: BoundedString<MIN, MAX, M>) -> Self {
+        bounded.into_non_empty_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> From<BoundedString<MIN, MAX, M>> for String {
+    fn from(bounded: BoundedString<MIN, MAX, M>) -> Self {
+        bounded.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> AsRef<Self> for BoundedString<MIN, MAX, M> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> AsRef<BoundedStr<MIN, MAX, M>>
+    for BoundedString<MIN, MAX, M>
+{
+    fn as_ref(&self) -> &BoundedStr<MIN, MAX, M> {
+        self.as_bounded_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> AsRef<NonEmptyStr> for BoundedString<MIN, MAX, M> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> AsRef<str> for BoundedString<MIN, MAX, M> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Deref for BoundedString<MIN, MAX, M> {
+    type Target = BoundedStr<MIN, MAX, M>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bounded_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M: Measure> BoundedString<MIN, MAX, M> {
+    /// Constructs [`Self`], provided that the [`String`] is in bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBoundsError`] if the string is out of bounds.
+    pub fn new(string: String) -> Result<Self, FromBoundsError> {
+        if let Err(error) = BoundedStr::<MIN, MAX, M>::check(&string) {
+            return Err(FromBoundsError::new(error, string));
+        }
+
+        // SAFETY: the string is non-empty and in bounds at this point
+        Ok(unsafe { Self::new_unchecked(string) })
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyString`], provided it is in bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBoundsError`] if the string is out of bounds.
+    pub fn from_non_empty_string(non_empty: NonEmptyString) -> Result<Self, FromBoundsError> {
+        Self::new(non_empty.into_string())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> BoundedString<MIN, MAX, M> {
+    /// Constructs [`Self`] without checking if the [`String`] is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and in bounds.
+    #[must_use]
+    pub const unsafe fn new_unchecked(string: String) -> Self {
+        let () = BoundedStr::<MIN, MAX, M>::BOUNDS;
+
+        // SAFETY: the caller must ensure that the string is non-empty
+        let inner = unsafe { NonEmptyString::new_unchecked(string) };
+
+        Self {
+            measure: PhantomData,
+            inner,
+        }
+    }
+
+    /// Constructs [`Self`] from [`BoundedStr`] via cloning.
+    #[must_use]
+    pub fn from_bounded_str(bounded: &BoundedStr<MIN, MAX, M>) -> Self {
+        // SAFETY: the string is non-empty and in bounds by construction
+        unsafe { Self::new_unchecked(String::from(bounded.as_str())) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.inner.as_non_empty_str()
+    }
+
+    /// Returns the contained string as [`BoundedStr`].
+    #[must_use]
+    pub const fn as_bounded_str(&self) -> &BoundedStr<MIN, MAX, M> {
+        // SAFETY: the string is non-empty and in bounds by construction
+        unsafe { BoundedStr::from_str_unchecked(self.as_str()) }
+    }
+
+    /// Returns the contained [`NonEmptyString`].
+    #[must_use]
+    pub const fn as_non_empty_string(&self) -> &NonEmptyString {
+        &self.inner
+    }
+
+    /// Returns the contained [`String`].
+    #[must_use]
+    pub const fn as_string(&self) -> &String {
+        self.inner.as_string()
+    }
+
+    /// Converts [`Self`] into the contained [`NonEmptyString`].
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.inner
+    }
+
+    /// Converts [`Self`] into the contained [`String`].
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> BoundedStr<MIN, MAX, M> {
+    /// Converts [`Self`] into [`BoundedString`].
+    #[must_use]
+    pub fn to_bounded_string(&self) -> BoundedString<MIN, MAX, M> {
+        BoundedString::from_bounded_str(self)
+    }
+}
@@ -0,0 +1,294 @@
+//! Non-empty [`CStr`].
+
+use core::{
+    ffi::{CStr, FromBytesWithNulError, c_char},
+    ops::Deref,
+    ptr,
+};
+
+use non_empty_slice::NonEmptyBytes;
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{
+    internal::{Bytes, attempt, map_error},
+    str::{NonEmptyStr, NonEmptyUtf8Error},
+};
+
+/// The error message used when the C string is empty.
+pub const EMPTY_C_STR: &str = "the C string is empty";
+
+/// Represents errors returned when received C strings are empty.
+#[derive(Debug, Error)]
+#[error("{EMPTY_C_STR}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::c_str),
+        help("make sure the C string is non-empty")
+    )
+)]
+pub struct EmptyCStr;
+
+/// Represents errors returned when the received bytes are either not nul-terminated,
+/// contain interior nul bytes or represent empty C strings.
+///
+/// This is returned from the [`from_bytes_with_nul`] method on [`NonEmptyCStr`].
+///
+/// [`from_bytes_with_nul`]: NonEmptyCStr::from_bytes_with_nul
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
+pub enum MaybeEmptyNulError {
+    /// The received bytes represent empty C string.
+    #[cfg_attr(feature = "diagnostics", diagnostic(transparent))]
+    Empty(#[from] EmptyCStr),
+    /// The received bytes are not nul-terminated or contain interior nul bytes.
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_str::c_str::nul),
+            help("make sure the bytes are nul-terminated and have no interior nul bytes")
+        )
+    )]
+    Nul(#[from] FromBytesWithNulError),
+}
+
+/// Represents non-empty [`CStr`] values.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyCStr {
+    inner: CStr,
+}
+
+impl<'c> TryFrom<&'c CStr> for &'c NonEmptyCStr {
+    type Error = EmptyCStr;
+
+    fn try_from(c_str: &'c CStr) -> Result<Self, Self::Error> {
+        NonEmptyCStr::try_from_c_str(c_str)
+    }
+}
+
+impl<'c> From<&'c NonEmptyCStr> for &'c CStr {
+    fn from(non_empty: &'c NonEmptyCStr) -> Self {
+        non_empty.as_c_str()
+    }
+}
+
+impl<'b> TryFrom<&'b Bytes> for &'b NonEmptyCStr {
+    type Error = MaybeEmptyNulError;
+
+    fn try_from(bytes: &'b Bytes) -> Result<Self, Self::Error> {
+        NonEmptyCStr::from_bytes_with_nul(bytes)
+    }
+}
+
+impl<'c> TryFrom<&'c NonEmptyCStr> for &'c NonEmptyStr {
+    type Error = NonEmptyUtf8Error;
+
+    fn try_from(non_empty: &'c NonEmptyCStr) -> Result<Self, Self::Error> {
+        non_empty.to_non_empty_str()
+    }
+}
+
+impl AsRef<Self> for NonEmptyCStr {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<CStr> for NonEmptyCStr {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl Deref for NonEmptyCStr {
+    type Target = CStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_c_str()
+    }
+}
+
+impl NonEmptyCStr {
+    /// Constructs [`Self`] from anything that can be converted to C string,
+    /// provided it is non-empty.
+    ///
+    /// Prefer [`try_from_c_str`] if only [`CStr`] is used, as this allows for `const` evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyCStr`] if the C string is empty.
+    ///
+    /// [`try_from_c_str`]: Self::try_from_c_str
+    pub fn try_new<S: AsRef<CStr> + ?Sized>(c_str: &S) -> Result<&Self, EmptyCStr> {
+        Self::try_from_c_str(c_str.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// Prefer [`from_c_str`] if only [`CStr`] is used, as this allows for `const` evaluation.
+    ///
+    /// [`try_new`]: Self::try_new
+    /// [`from_c_str`]: Self::from_c_str
+    pub fn new<S: AsRef<CStr> + ?Sized>(c_str: &S) -> Option<&Self> {
+        Self::from_c_str(c_str.as_ref())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to C string,
+    /// without doing any checks.
+    ///
+    /// Prefer [`from_c_str_unchecked`] if only [`CStr`] is used;
+    /// this allows for `const` evaluation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the C string is non-empty.
+    ///
+    /// [`from_c_str_unchecked`]: Self::from_c_str_unchecked
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<CStr> + ?Sized>(c_str: &S) -> &Self {
+        // SAFETY: the caller must ensure that the C string is non-empty
+        unsafe { Self::from_c_str_unchecked(c_str.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`CStr`], provided the C string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyCStr`] if the C string is empty.
+    pub const fn try_from_c_str(c_str: &CStr) -> Result<&Self, EmptyCStr> {
+        if c_str.is_empty() {
+            return Err(EmptyCStr);
+        }
+
+        // SAFETY: the C string is non-empty at this point
+        Ok(unsafe { Self::from_c_str_unchecked(c_str) })
+    }
+
+    /// Similar to [`try_from_c_str`], but the error is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::NonEmptyCStr;
+    ///
+    /// let device = NonEmptyCStr::from_c_str(c"eth0").unwrap();
+    ///
+    /// assert!(NonEmptyCStr::from_c_str(c"").is_none());
+    /// ```
+    ///
+    /// [`try_from_c_str`]: Self::try_from_c_str
+    #[must_use]
+    pub const fn from_c_str(c_str: &CStr) -> Option<&Self> {
+        if c_str.is_empty() {
+            return None;
+        }
+
+        // SAFETY: the C string is non-empty at this point
+        Some(unsafe { Self::from_c_str_unchecked(c_str) })
+    }
+
+    /// Constructs [`Self`] from [`CStr`], without checking if the C string is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the C string is non-empty.
+    #[must_use]
+    pub const unsafe fn from_c_str_unchecked(c_str: &CStr) -> &Self {
+        debug_assert!(!c_str.is_empty());
+
+        // SAFETY: the caller must ensure that the C string is non-empty
+        // `Self` is `repr(transparent)`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(c_str) as *const Self) }
+    }
+
+    /// Constructs [`Self`] from the given nul-terminated bytes,
+    /// provided they contain no interior nul bytes and represent non-empty C string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyNulError`] if the bytes are not nul-terminated,
+    /// contain interior nul bytes or represent empty C string.
+    pub const fn from_bytes_with_nul(bytes: &Bytes) -> Result<&Self, MaybeEmptyNulError> {
+        let c_str =
+            attempt!(map_error!(CStr::from_bytes_with_nul(bytes) => MaybeEmptyNulError::Nul));
+
+        map_error!(Self::try_from_c_str(c_str) => MaybeEmptyNulError::Empty)
+    }
+
+    /// Constructs [`Self`] from the given nul-terminated bytes without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are nul-terminated, contain no interior nul bytes
+    /// and represent non-empty C string, that is, contain at least two bytes.
+    #[must_use]
+    pub const unsafe fn from_bytes_with_nul_unchecked(bytes: &Bytes) -> &Self {
+        // SAFETY: the caller must ensure that the bytes are valid non-empty C string
+        unsafe { Self::from_c_str_unchecked(CStr::from_bytes_with_nul_unchecked(bytes)) }
+    }
+
+    /// Returns the contained C string.
+    #[must_use]
+    pub const fn as_c_str(&self) -> &CStr {
+        &self.inner
+    }
+
+    /// Checks if the C string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the C string is never empty.
+    #[deprecated = "this C string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the C string in bytes as [`Size`], excluding the nul terminator.
+    #[must_use]
+    pub const fn count_bytes(&self) -> Size {
+        let count = self.as_c_str().count_bytes();
+
+        // SAFETY: the C string is non-empty by construction, so its length is non-zero
+        unsafe { Size::new_unchecked(count) }
+    }
+
+    /// Returns the inner pointer to the C string.
+    ///
+    /// Refer to [`CStr::as_ptr`] for more details on the pointer validity.
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.as_c_str().as_ptr()
+    }
+
+    /// Returns the underlying bytes of the C string, excluding the nul terminator.
+    #[must_use]
+    pub const fn to_bytes(&self) -> &Bytes {
+        self.as_c_str().to_bytes()
+    }
+
+    /// Returns the underlying bytes of the C string as [`NonEmptyBytes`],
+    /// excluding the nul terminator.
+    #[must_use]
+    pub const fn to_non_empty_bytes(&self) -> &NonEmptyBytes {
+        // SAFETY: the C string is non-empty by construction, so are its bytes
+        unsafe { NonEmptyBytes::from_slice_unchecked(self.to_bytes()) }
+    }
+
+    /// Returns the underlying bytes of the C string, including the nul terminator.
+    #[must_use]
+    pub const fn to_bytes_with_nul(&self) -> &Bytes {
+        self.as_c_str().to_bytes_with_nul()
+    }
+
+    /// Converts the C string to [`NonEmptyStr`], provided it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonEmptyUtf8Error`] if the C string is not valid UTF-8.
+    pub const fn to_non_empty_str(&self) -> Result<&NonEmptyStr, NonEmptyUtf8Error> {
+        NonEmptyStr::from_non_empty_utf8(self.to_non_empty_bytes())
+    }
+}
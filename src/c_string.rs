@@ -0,0 +1,408 @@
+//! Non-empty [`CString`].
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::Cow,
+    ffi::{CString, IntoStringError, NulError},
+};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    ffi::{CString, IntoStringError, NulError},
+    string::String,
+};
+
+use core::{borrow::Borrow, ffi::CStr, ops::Deref};
+
+use non_empty_slice::{EmptyByteVec, NonEmptyByteVec};
+use thiserror::Error;
+
+use crate::{
+    c_str::NonEmptyCStr,
+    cow::NonEmptyCowStr,
+    internal::ByteVec,
+    str::NonEmptyStr,
+    string::{EmptyString, NonEmptyString},
+};
+
+/// The error message used when the owned C string is empty.
+pub const EMPTY_C_STRING: &str = "the C string is empty";
+
+/// Similar to [`EmptyCStr`], but holds the empty C string provided.
+///
+/// [`EmptyCStr`]: crate::c_str::EmptyCStr
+#[derive(Debug, Error)]
+#[error("{EMPTY_C_STRING}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::c_string),
+        help("make sure the C string is non-empty")
+    )
+)]
+pub struct EmptyCString {
+    c_string: CString,
+}
+
+impl EmptyCString {
+    // NOTE: this is private to prevent creating this error with non-empty C strings
+    pub(crate) const fn new(c_string: CString) -> Self {
+        Self { c_string }
+    }
+
+    /// Returns the contained empty C string.
+    #[must_use]
+    pub fn get(self) -> CString {
+        self.c_string
+    }
+}
+
+/// Represents errors returned when the provided byte vector is empty or contains nul bytes.
+///
+/// This is returned from the [`from_vec`] method on [`NonEmptyCString`].
+///
+/// [`from_vec`]: NonEmptyCString::from_vec
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
+pub enum FromMaybeEmptyNulError {
+    /// The received byte vector is empty.
+    #[cfg_attr(feature = "diagnostics", diagnostic(transparent))]
+    Empty(#[from] EmptyByteVec),
+    /// The received byte vector is non-empty, but contains nul bytes.
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_str::c_string::nul),
+            help("make sure the bytes contain no nul bytes")
+        )
+    )]
+    Nul(#[from] NulError),
+}
+
+/// Represents errors returned when the provided string is empty or contains nul bytes.
+///
+/// This is returned from the [`from_string`] method on [`NonEmptyCString`].
+///
+/// [`from_string`]: NonEmptyCString::from_string
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
+pub enum FromMaybeEmptyStringNulError {
+    /// The received string is empty.
+    #[cfg_attr(feature = "diagnostics", diagnostic(transparent))]
+    Empty(#[from] EmptyString),
+    /// The received string is non-empty, but contains nul bytes.
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_str::c_string::nul),
+            help("make sure the string contains no nul bytes")
+        )
+    )]
+    Nul(#[from] NulError),
+}
+
+/// Represents non-empty [`CString`] values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyCString {
+    inner: CString,
+}
+
+impl Borrow<NonEmptyCStr> for NonEmptyCString {
+    fn borrow(&self) -> &NonEmptyCStr {
+        self.as_non_empty_c_str()
+    }
+}
+
+impl Borrow<CStr> for NonEmptyCString {
+    fn borrow(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl TryFrom<CString> for NonEmptyCString {
+    type Error = EmptyCString;
+
+    fn try_from(c_string: CString) -> Result<Self, Self::Error> {
+        Self::new(c_string)
+    }
+}
+
+impl From<NonEmptyCString> for CString {
+    fn from(non_empty: NonEmptyCString) -> Self {
+        non_empty.into_c_string()
+    }
+}
+
+impl From<&NonEmptyCStr> for NonEmptyCString {
+    fn from(non_empty: &NonEmptyCStr) -> Self {
+        non_empty.to_non_empty_c_string()
+    }
+}
+
+impl TryFrom<&NonEmptyStr> for NonEmptyCString {
+    type Error = NulError;
+
+    fn try_from(non_empty: &NonEmptyStr) -> Result<Self, Self::Error> {
+        Self::from_non_empty_str(non_empty)
+    }
+}
+
+impl TryFrom<NonEmptyString> for NonEmptyCString {
+    type Error = NulError;
+
+    fn try_from(non_empty: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+impl TryFrom<NonEmptyCString> for NonEmptyString {
+    type Error = IntoStringError;
+
+    fn try_from(non_empty: NonEmptyCString) -> Result<Self, Self::Error> {
+        non_empty.into_non_empty_string()
+    }
+}
+
+impl AsRef<Self> for NonEmptyCString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyCStr> for NonEmptyCString {
+    fn as_ref(&self) -> &NonEmptyCStr {
+        self.as_non_empty_c_str()
+    }
+}
+
+impl AsRef<CStr> for NonEmptyCString {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl Deref for NonEmptyCString {
+    type Target = NonEmptyCStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_c_str()
+    }
+}
+
+impl ToOwned for NonEmptyCStr {
+    type Owned = NonEmptyCString;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_empty_c_string()
+    }
+}
+
+impl NonEmptyCString {
+    /// Constructs [`Self`], provided that the [`CString`] is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyCString`] if the C string is empty.
+    pub fn new(c_string: CString) -> Result<Self, EmptyCString> {
+        if c_string.is_empty() {
+            return Err(EmptyCString::new(c_string));
+        }
+
+        // SAFETY: the C string is non-empty at this point
+        Ok(unsafe { Self::new_unchecked(c_string) })
+    }
+
+    /// Constructs [`Self`] without checking if the C string is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the C string is non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: CString) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyCStr`] via cloning.
+    #[must_use]
+    pub fn from_non_empty_c_str(non_empty: &NonEmptyCStr) -> Self {
+        // SAFETY: the C string is non-empty by construction
+        unsafe { Self::new_unchecked(non_empty.as_c_str().to_owned()) }
+    }
+
+    /// Constructs [`Self`] from the given byte vector,
+    /// provided it is non-empty and contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromMaybeEmptyNulError`] if the byte vector is empty or contains nul bytes.
+    pub fn from_vec(bytes: ByteVec) -> Result<Self, FromMaybeEmptyNulError> {
+        let non_empty = NonEmptyByteVec::new(bytes)?;
+
+        let c_string = Self::from_non_empty_vec(non_empty)?;
+
+        Ok(c_string)
+    }
+
+    /// Constructs [`Self`] from the given [`NonEmptyByteVec`], provided it contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the byte vector contains nul bytes.
+    pub fn from_non_empty_vec(non_empty: NonEmptyByteVec) -> Result<Self, NulError> {
+        let c_string = CString::new(non_empty.into_vec())?;
+
+        // SAFETY: the bytes are non-empty by construction, so is the resulting C string
+        Ok(unsafe { Self::new_unchecked(c_string) })
+    }
+
+    /// Constructs [`Self`] from the given string, provided it is non-empty and has no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromMaybeEmptyStringNulError`] if the string is empty or contains nul bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::{FromMaybeEmptyStringNulError, NonEmptyCString};
+    ///
+    /// let interface = NonEmptyCString::from_string("wlan0".to_owned()).unwrap();
+    ///
+    /// let empty = NonEmptyCString::from_string(String::new()).unwrap_err();
+    ///
+    /// assert!(matches!(empty, FromMaybeEmptyStringNulError::Empty(_)));
+    ///
+    /// let nul = NonEmptyCString::from_string("wlan\0".to_owned()).unwrap_err();
+    ///
+    /// assert!(matches!(nul, FromMaybeEmptyStringNulError::Nul(_)));
+    /// ```
+    pub fn from_string(string: String) -> Result<Self, FromMaybeEmptyStringNulError> {
+        let non_empty = NonEmptyString::new(string)?;
+
+        let c_string = Self::from_non_empty_string(non_empty)?;
+
+        Ok(c_string)
+    }
+
+    /// Constructs [`Self`] from the given [`NonEmptyString`], provided it contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the string contains nul bytes.
+    pub fn from_non_empty_string(non_empty: NonEmptyString) -> Result<Self, NulError> {
+        Self::from_non_empty_vec(non_empty.into_non_empty_bytes())
+    }
+
+    /// Constructs [`Self`] from the given [`NonEmptyStr`] via cloning,
+    /// provided it contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the string contains nul bytes.
+    pub fn from_non_empty_str(non_empty: &NonEmptyStr) -> Result<Self, NulError> {
+        Self::from_non_empty_vec(non_empty.to_non_empty_bytes())
+    }
+
+    /// Returns the contained C string reference.
+    #[must_use]
+    pub const fn as_c_string(&self) -> &CString {
+        &self.inner
+    }
+
+    /// Returns the contained [`CString`].
+    #[must_use]
+    pub fn into_c_string(self) -> CString {
+        self.inner
+    }
+
+    /// Extracts the C string slice containing the entire C string.
+    #[must_use]
+    pub fn as_c_str(&self) -> &CStr {
+        self.as_c_string().as_c_str()
+    }
+
+    /// Returns contained C string reference as [`NonEmptyCStr`].
+    #[must_use]
+    pub fn as_non_empty_c_str(&self) -> &NonEmptyCStr {
+        // SAFETY: the C string is non-empty by construction
+        unsafe { NonEmptyCStr::from_c_str_unchecked(self.as_c_str()) }
+    }
+
+    /// Converts [`Self`] into the underlying [`NonEmptyByteVec`], excluding the nul terminator.
+    #[must_use]
+    pub fn into_non_empty_bytes(self) -> NonEmptyByteVec {
+        // SAFETY: the C string is non-empty by construction, so are its bytes
+        unsafe { NonEmptyByteVec::new_unchecked(self.into_c_string().into_bytes()) }
+    }
+
+    /// Converts [`Self`] into the underlying byte vector, including the nul terminator.
+    #[must_use]
+    pub fn into_bytes_with_nul(self) -> ByteVec {
+        self.into_c_string().into_bytes_with_nul()
+    }
+
+    /// Converts [`Self`] into [`NonEmptyString`], provided it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntoStringError`] if the C string is not valid UTF-8.
+    pub fn into_non_empty_string(self) -> Result<NonEmptyString, IntoStringError> {
+        let string = self.into_c_string().into_string()?;
+
+        // SAFETY: the C string is non-empty by construction, so is the resulting string
+        Ok(unsafe { NonEmptyString::new_unchecked(string) })
+    }
+}
+
+impl NonEmptyCStr {
+    /// Converts [`Self`] to [`NonEmptyCString`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_c_string(&self) -> NonEmptyCString {
+        NonEmptyCString::from_non_empty_c_str(self)
+    }
+
+    /// Converts the C string to [`NonEmptyCowStr`], including invalid characters.
+    ///
+    /// Any invalid UTF-8 sequences will be replaced with [`char::REPLACEMENT_CHARACTER`].
+    #[must_use]
+    pub fn to_string_lossy(&self) -> NonEmptyCowStr<'_> {
+        match self.as_c_str().to_string_lossy() {
+            // SAFETY: converting non-empty C string results in non-empty lossy string
+            Cow::Owned(string) => Cow::Owned(unsafe { NonEmptyString::new_unchecked(string) }),
+            Cow::Borrowed(string) => {
+                // SAFETY: converting non-empty C string results in non-empty lossy string
+                Cow::Borrowed(unsafe { NonEmptyStr::from_str_unchecked(string) })
+            }
+        }
+    }
+}
+
+impl NonEmptyStr {
+    /// Converts [`Self`] to [`NonEmptyCString`] via cloning, provided it contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the string contains nul bytes.
+    pub fn to_non_empty_c_string(&self) -> Result<NonEmptyCString, NulError> {
+        NonEmptyCString::from_non_empty_str(self)
+    }
+}
+
+impl NonEmptyString {
+    /// Converts [`Self`] into [`NonEmptyCString`], provided it contains no nul bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if the string contains nul bytes.
+    pub fn into_non_empty_c_string(self) -> Result<NonEmptyCString, NulError> {
+        NonEmptyCString::from_non_empty_string(self)
+    }
+}
@@ -0,0 +1,77 @@
+//! Case conversion of non-empty strings.
+
+#[cfg(not(feature = "heck"))]
+compile_error!("expected `heck` to be enabled");
+
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+use crate::{
+    str::NonEmptyStr,
+    string::{EmptyString, NonEmptyString},
+};
+
+impl NonEmptyStr {
+    /// Converts this string to `snake_case`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the converted string is empty, which happens
+    /// when this string contains no alphanumeric characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let name = non_empty_str!("NonEmptyStr");
+    ///
+    /// assert_eq!(name.to_snake_case().unwrap().as_str(), "non_empty_str");
+    ///
+    /// let underscores = non_empty_str!("___");
+    ///
+    /// assert!(underscores.to_snake_case().is_err());
+    /// ```
+    pub fn to_snake_case(&self) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().to_snake_case())
+    }
+
+    /// Converts this string to `kebab-case`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the converted string is empty, which happens
+    /// when this string contains no alphanumeric characters.
+    pub fn to_kebab_case(&self) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().to_kebab_case())
+    }
+
+    /// Converts this string to `lowerCamelCase`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the converted string is empty, which happens
+    /// when this string contains no alphanumeric characters.
+    pub fn to_lower_camel_case(&self) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().to_lower_camel_case())
+    }
+
+    /// Converts this string to `UpperCamelCase`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the converted string is empty, which happens
+    /// when this string contains no alphanumeric characters.
+    pub fn to_upper_camel_case(&self) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().to_upper_camel_case())
+    }
+
+    /// Converts this string to `SHOUTY_SNAKE_CASE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the converted string is empty, which happens
+    /// when this string contains no alphanumeric characters.
+    pub fn to_shouty_snake_case(&self) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().to_shouty_snake_case())
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(not(feature = "diesel"))]
+compile_error!("expected `diesel` to be enabled");
+
+use std::string::String;
+
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, Queryable},
+    expression::AsExpression,
+    serialize::{self, Output, ToSql},
+    sql_types::{Nullable, Text},
+};
+
+use crate::{boxed::NonEmptyBoxedStr, str::NonEmptyStr, string::NonEmptyString};
+
+// NOTE: expressions are built from the standard string ones, since `diesel` does not expose
+// the underlying bound expression type; there is no `FromSql` for `&NonEmptyStr`, because
+// `diesel` does not support deserializing borrowed strings either
+
+macro_rules! impl_as_expression {
+    (<$lifetime: lifetime> $type: ty => $target: ty, $convert: expr) => {
+        impl_as_expression!(@impl [<$lifetime>] $type => $target, $convert, Text);
+        impl_as_expression!(@impl [<$lifetime>] $type => $target, $convert, Nullable<Text>);
+    };
+    ($type: ty => $target: ty, $convert: expr) => {
+        impl_as_expression!(@impl [] $type => $target, $convert, Text);
+        impl_as_expression!(@impl [] $type => $target, $convert, Nullable<Text>);
+    };
+    (@impl [$($generics: tt)*] $type: ty => $target: ty, $convert: expr, $sql_type: ty) => {
+        impl $($generics)* AsExpression<$sql_type> for $type {
+            type Expression = <$target as AsExpression<$sql_type>>::Expression;
+
+            fn as_expression(self) -> Self::Expression {
+                let convert: fn($type) -> $target = $convert;
+
+                <$target as AsExpression<$sql_type>>::as_expression(convert(self))
+            }
+        }
+    };
+}
+
+impl_as_expression!(<'s> &'s NonEmptyStr => &'s str, NonEmptyStr::as_str);
+
+impl_as_expression!(NonEmptyString => String, NonEmptyString::into_string);
+
+impl_as_expression!(<'s> &'s NonEmptyString => &'s String, NonEmptyString::as_string);
+
+impl_as_expression!(NonEmptyBoxedStr => String, |boxed| boxed.into_non_empty_string().into_string());
+
+impl_as_expression!(<'s> &'s NonEmptyBoxedStr => &'s str, |boxed| boxed.as_str());
+
+impl<D: Backend> ToSql<Text, D> for NonEmptyStr
+where
+    str: ToSql<Text, D>,
+{
+    fn to_sql<'b>(&'b self, output: &mut Output<'b, '_, D>) -> serialize::Result {
+        self.as_str().to_sql(output)
+    }
+}
+
+impl<D: Backend> ToSql<Text, D> for NonEmptyString
+where
+    str: ToSql<Text, D>,
+{
+    fn to_sql<'b>(&'b self, output: &mut Output<'b, '_, D>) -> serialize::Result {
+        self.as_str().to_sql(output)
+    }
+}
+
+impl<D: Backend> ToSql<Text, D> for NonEmptyBoxedStr
+where
+    str: ToSql<Text, D>,
+{
+    fn to_sql<'b>(&'b self, output: &mut Output<'b, '_, D>) -> serialize::Result {
+        self.as_str().to_sql(output)
+    }
+}
+
+impl<D: Backend> FromSql<Text, D> for NonEmptyString
+where
+    String: FromSql<Text, D>,
+{
+    fn from_sql(bytes: D::RawValue<'_>) -> deserialize::Result<Self> {
+        let string = String::from_sql(bytes)?;
+
+        let non_empty = Self::new(string)?;
+
+        Ok(non_empty)
+    }
+}
+
+impl<D: Backend> FromSql<Text, D> for NonEmptyBoxedStr
+where
+    String: FromSql<Text, D>,
+{
+    fn from_sql(bytes: D::RawValue<'_>) -> deserialize::Result<Self> {
+        NonEmptyString::from_sql(bytes).map(NonEmptyString::into_non_empty_boxed_str)
+    }
+}
+
+impl<D: Backend> Queryable<Text, D> for NonEmptyString
+where
+    Self: FromSql<Text, D>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<D: Backend> Queryable<Text, D> for NonEmptyBoxedStr
+where
+    Self: FromSql<Text, D>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
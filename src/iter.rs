@@ -1,10 +1,19 @@
 //! Various iterators over non-empty strings.
 
-use core::{iter::Map, str};
+use core::{
+    iter::{FusedIterator, Map},
+    str,
+};
 
 use non_empty_iter::NonEmptyIterator;
 
-use crate::{internal::Byte, str::NonEmptyStr};
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    internal::Byte,
+    str::{NonEmptyStr, Separator},
+};
 
 /// Represents functions mapping non-empty [`prim@str`] to [`NonEmptyStr`].
 ///
@@ -12,6 +21,12 @@ use crate::{internal::Byte, str::NonEmptyStr};
 /// one needs to name the type of the iterator explicitly.
 pub type NonEmptyStrFn<'s> = fn(&'s str) -> &'s NonEmptyStr;
 
+/// Represents functions mapping indexed non-empty [`prim@str`] to indexed [`NonEmptyStr`].
+///
+/// Similar to [`NonEmptyStrFn`], this is mostly an implementation detail.
+#[cfg(feature = "unicode-segmentation")]
+pub type IndexedNonEmptyStrFn<'s> = fn((usize, &'s str)) -> (usize, &'s NonEmptyStr);
+
 /// Represents non-empty iterators over the bytes in non-empty strings.
 ///
 /// This `struct` is created by the [`bytes`] method on [`NonEmptyStr`].
@@ -318,3 +333,344 @@ impl<'s> IntoIterator for Lines<'s> {
 }
 
 unsafe impl NonEmptyIterator for Lines<'_> {}
+
+macro_rules! split_non_empty {
+    (
+        $(#[$meta: meta])*
+        $name: ident => $split: ident::$method: ident $(($count: ident))?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $name<'s, S: Separator> {
+            inner: S::$split<'s>,
+        }
+
+        impl<'s, S: Separator> $name<'s, S> {
+            /// Constructs [`Self`].
+            #[must_use]
+            pub fn new(string: &'s NonEmptyStr, $($count: usize,)? separator: S) -> Self {
+                Self {
+                    inner: separator.$method(string.as_str() $(, $count)?),
+                }
+            }
+        }
+
+        impl<'s, S: Separator> Iterator for $name<'s, S> {
+            type Item = &'s NonEmptyStr;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.find_map(NonEmptyStr::from_str)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let (_, upper) = self.inner.size_hint();
+
+                (0, upper)
+            }
+        }
+
+        impl<'s, S: Separator> DoubleEndedIterator for $name<'s, S>
+        where
+            S::$split<'s>: DoubleEndedIterator,
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.by_ref().rev().find_map(NonEmptyStr::from_str)
+            }
+        }
+
+        impl<'s, S: Separator> FusedIterator for $name<'s, S> {}
+    };
+}
+
+split_non_empty! {
+    /// Represents iterators over the non-empty substrings of non-empty strings,
+    /// separated by the given [`Separator`].
+    ///
+    /// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be
+    /// empty, specifically, if the input string consists of separators only.
+    ///
+    /// This `struct` is created by the [`split_non_empty`] method on [`NonEmptyStr`].
+    ///
+    /// [`split_non_empty`]: NonEmptyStr::split_non_empty
+    SplitNonEmpty => Split::split
+}
+
+split_non_empty! {
+    /// Represents iterators over the non-empty substrings of non-empty strings,
+    /// separated by the given [`Separator`] and yielded in reverse order.
+    ///
+    /// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be
+    /// empty, specifically, if the input string consists of separators only.
+    ///
+    /// This `struct` is created by the [`rsplit_non_empty`] method on [`NonEmptyStr`].
+    ///
+    /// [`rsplit_non_empty`]: NonEmptyStr::rsplit_non_empty
+    RSplitNonEmpty => RSplit::rsplit
+}
+
+split_non_empty! {
+    /// Represents iterators over the non-empty substrings of non-empty strings,
+    /// terminated by the given [`Separator`].
+    ///
+    /// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be
+    /// empty, specifically, if the input string consists of separators only.
+    ///
+    /// This `struct` is created by the [`split_terminator_non_empty`] method on [`NonEmptyStr`].
+    ///
+    /// [`split_terminator_non_empty`]: NonEmptyStr::split_terminator_non_empty
+    SplitTerminatorNonEmpty => SplitTerminator::split_terminator
+}
+
+split_non_empty! {
+    /// Represents iterators over the non-empty substrings of non-empty strings,
+    /// separated by the given [`Separator`], splitting at most `count - 1` times.
+    ///
+    /// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be
+    /// empty, specifically, if the input string consists of separators only or `count` is zero.
+    ///
+    /// This `struct` is created by the [`splitn_non_empty`] method on [`NonEmptyStr`].
+    ///
+    /// [`splitn_non_empty`]: NonEmptyStr::splitn_non_empty
+    SplitNNonEmpty => SplitN::splitn(count)
+}
+
+/// Represents non-empty iterators over the grapheme clusters in non-empty strings.
+///
+/// This `struct` is created by the [`graphemes`] method on [`NonEmptyStr`].
+///
+/// [`graphemes`]: NonEmptyStr::graphemes
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct Graphemes<'s> {
+    string: &'s NonEmptyStr,
+    is_extended: bool,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> Graphemes<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr, is_extended: bool) -> Self {
+        Self {
+            string,
+            is_extended,
+        }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for Graphemes<'s> {
+    type Item = &'s NonEmptyStr;
+    type IntoIter = Map<unicode_segmentation::Graphemes<'s>, NonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .graphemes(self.is_extended)
+            // SAFETY: grapheme clusters are never empty
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+unsafe impl NonEmptyIterator for Graphemes<'_> {}
+
+/// Represents non-empty iterators over the grapheme clusters and their positions
+/// in non-empty strings.
+///
+/// This `struct` is created by the [`grapheme_indices`] method on [`NonEmptyStr`].
+///
+/// [`grapheme_indices`]: NonEmptyStr::grapheme_indices
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct GraphemeIndices<'s> {
+    string: &'s NonEmptyStr,
+    is_extended: bool,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> GraphemeIndices<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr, is_extended: bool) -> Self {
+        Self {
+            string,
+            is_extended,
+        }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for GraphemeIndices<'s> {
+    type Item = (usize, &'s NonEmptyStr);
+    type IntoIter = Map<unicode_segmentation::GraphemeIndices<'s>, IndexedNonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .grapheme_indices(self.is_extended)
+            // SAFETY: grapheme clusters are never empty
+            .map(|(index, string)| (index, unsafe { NonEmptyStr::from_str_unchecked(string) }))
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+unsafe impl NonEmptyIterator for GraphemeIndices<'_> {}
+
+/// Represents iterators over the words in non-empty strings,
+/// according to the Unicode word boundaries.
+///
+/// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be empty,
+/// specifically, if the input string contains no alphanumeric characters.
+///
+/// This `struct` is created by the [`unicode_words`] method on [`NonEmptyStr`].
+///
+/// [`unicode_words`]: NonEmptyStr::unicode_words
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct UnicodeWords<'s> {
+    string: &'s NonEmptyStr,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> UnicodeWords<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr) -> Self {
+        Self { string }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for UnicodeWords<'s> {
+    type Item = &'s NonEmptyStr;
+    type IntoIter = Map<unicode_segmentation::UnicodeWords<'s>, NonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .unicode_words()
+            // SAFETY: words are never empty
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+}
+
+// NOTE: `UnicodeWords<'_>` does not implement `NonEmptyIterator` as it can be empty,
+// specifically, if the input string contains no alphanumeric characters
+
+/// Represents non-empty iterators over the substrings of non-empty strings,
+/// separated by the Unicode word boundaries.
+///
+/// This `struct` is created by the [`split_word_bounds`] method on [`NonEmptyStr`].
+///
+/// [`split_word_bounds`]: NonEmptyStr::split_word_bounds
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct SplitWordBounds<'s> {
+    string: &'s NonEmptyStr,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> SplitWordBounds<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr) -> Self {
+        Self { string }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for SplitWordBounds<'s> {
+    type Item = &'s NonEmptyStr;
+    type IntoIter = Map<unicode_segmentation::UWordBounds<'s>, NonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .split_word_bounds()
+            // SAFETY: substrings between word boundaries are never empty
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+unsafe impl NonEmptyIterator for SplitWordBounds<'_> {}
+
+/// Represents iterators over the sentences in non-empty strings,
+/// according to the Unicode sentence boundaries.
+///
+/// Note that this `struct` does not implement [`NonEmptyIterator`] as the iterator can be empty,
+/// specifically, if the input string contains no alphanumeric characters.
+///
+/// This `struct` is created by the [`unicode_sentences`] method on [`NonEmptyStr`].
+///
+/// [`unicode_sentences`]: NonEmptyStr::unicode_sentences
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct UnicodeSentences<'s> {
+    string: &'s NonEmptyStr,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> UnicodeSentences<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr) -> Self {
+        Self { string }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for UnicodeSentences<'s> {
+    type Item = &'s NonEmptyStr;
+    type IntoIter = Map<unicode_segmentation::UnicodeSentences<'s>, NonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .unicode_sentences()
+            // SAFETY: sentences are never empty
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+}
+
+// NOTE: `UnicodeSentences<'_>` does not implement `NonEmptyIterator` as it can be empty,
+// specifically, if the input string contains no alphanumeric characters
+
+/// Represents non-empty iterators over the substrings of non-empty strings,
+/// separated by the Unicode sentence boundaries.
+///
+/// This `struct` is created by the [`split_sentence_bounds`] method on [`NonEmptyStr`].
+///
+/// [`split_sentence_bounds`]: NonEmptyStr::split_sentence_bounds
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug)]
+pub struct SplitSentenceBounds<'s> {
+    string: &'s NonEmptyStr,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> SplitSentenceBounds<'s> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(string: &'s NonEmptyStr) -> Self {
+        Self { string }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'s> IntoIterator for SplitSentenceBounds<'s> {
+    type Item = &'s NonEmptyStr;
+    type IntoIter = Map<unicode_segmentation::USentenceBounds<'s>, NonEmptyStrFn<'s>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.string
+            .as_str()
+            .split_sentence_bounds()
+            // SAFETY: substrings between sentence boundaries are never empty
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+unsafe impl NonEmptyIterator for SplitSentenceBounds<'_> {}
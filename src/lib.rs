@@ -15,7 +15,56 @@ pub mod str;
 pub mod iter;
 
 #[doc(inline)]
-pub use str::{EmptyStr, FromNonEmptyStr, MaybeEmptyUtf8Error, NonEmptyStr, NonEmptyUtf8Error};
+pub use str::{
+    EmptyStr, FromNonEmptyStr, MaybeEmptyUtf8Error, MissingAffix, NonEmptyStr, NonEmptyUtf8Error,
+    Separator, StripError,
+};
+
+pub mod blank;
+
+#[doc(inline)]
+pub use blank::{BlankStr, NonBlankStr};
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use blank::{BlankString, NonBlankString};
+
+pub mod ascii;
+
+#[doc(inline)]
+pub use ascii::{MaybeEmptyAsciiError, NonAsciiStr, NonEmptyAsciiStr};
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use ascii::{FromMaybeEmptyAsciiError, NonAsciiString, NonEmptyAsciiString};
+
+pub mod bounded;
+
+#[doc(inline)]
+pub use bounded::{BoundedStr, BoundsError};
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use bounded::{BoundedString, FromBoundsError};
+
+pub mod validated;
+
+#[doc(inline)]
+pub use validated::{MaybeEmptyValidationError, ValidatedStr, Validator};
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use validated::{FromMaybeEmptyValidationError, FromValidationError, ValidatedString};
+
+pub mod array_string;
+
+#[doc(inline)]
+pub use array_string::{CapacityError, MaybeEmptyCapacityError, NonEmptyArrayString};
+
+pub mod c_str;
+
+#[doc(inline)]
+pub use c_str::{EmptyCStr, MaybeEmptyNulError, NonEmptyCStr};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod string;
@@ -24,6 +73,13 @@ pub mod string;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use string::{EmptyString, FromMaybeEmptyUtf8Error, FromNonEmptyUtf8Error, NonEmptyString};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod small_string;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use small_string::{NonEmptyInlineString, NonEmptySmallString};
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod boxed;
 
@@ -31,6 +87,20 @@ pub mod boxed;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use boxed::{EmptyBoxedStr, NonEmptyBoxedStr};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod rc;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use rc::{EmptyRcStr, NonEmptyRcStr};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod arc;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use arc::{EmptyArcStr, NonEmptyArcStr};
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod cow;
 
@@ -38,10 +108,73 @@ pub mod cow;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use cow::NonEmptyCowStr;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod c_string;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use c_string::{
+    EmptyCString, FromMaybeEmptyNulError, FromMaybeEmptyStringNulError, NonEmptyCString,
+};
+
+#[cfg(feature = "unicode-normalization")]
+pub mod normalization;
+
+#[doc(inline)]
+#[cfg(feature = "unicode-normalization")]
+pub use normalization::NormalizedNonEmptyString;
+
+#[cfg(feature = "unicode-width")]
+pub mod width;
+
+#[doc(inline)]
+#[cfg(feature = "unicode-width")]
+pub use width::Alignment;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "std")]
+pub mod os;
+
+#[doc(inline)]
+#[cfg(feature = "std")]
+pub use os::{EmptyOsStr, EmptyOsString, NonEmptyOsStr, NonEmptyOsString};
+
+#[cfg(feature = "std")]
+pub mod path;
+
+#[doc(inline)]
+#[cfg(feature = "std")]
+pub use path::{EmptyPath, EmptyPathBuf, NonEmptyPath, NonEmptyPathBuf};
+
 #[cfg(feature = "ownership")]
 pub(crate) mod ownership;
 
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
 
+#[cfg(feature = "unicode-segmentation")]
+pub(crate) mod segmentation;
+
+#[cfg(feature = "heck")]
+pub(crate) mod case;
+
+#[cfg(feature = "schemars")]
+pub(crate) mod schemars;
+
+#[cfg(feature = "arbitrary")]
+pub(crate) mod arbitrary;
+
+#[cfg(feature = "sqlx")]
+pub(crate) mod sqlx;
+
+#[cfg(feature = "diesel")]
+pub(crate) mod diesel;
+
+#[cfg(feature = "rusqlite")]
+pub(crate) mod rusqlite;
+
+pub(crate) mod folding;
+
 pub(crate) mod internal;
@@ -61,3 +61,120 @@ macro_rules! const_non_empty_str {
         const { $crate::non_empty_str!($string) }
     };
 }
+
+/// Constructs [`NonEmptyCStr`] from the given C string, panicking if the it is empty.
+///
+/// # Examples
+///
+/// Simple usage:
+///
+/// ```
+/// use non_empty_str::non_empty_c_str;
+///
+/// let device = non_empty_c_str!(c"eth0");
+/// ```
+///
+/// Panicking if the C string is empty:
+///
+/// ```should_panic
+/// use non_empty_str::non_empty_c_str;
+///
+/// let never = non_empty_c_str!(c"");
+/// ```
+///
+/// [`NonEmptyCStr`]: crate::c_str::NonEmptyCStr
+#[macro_export]
+macro_rules! non_empty_c_str {
+    ($c_str: expr) => {
+        $crate::c_str::NonEmptyCStr::from_c_str($c_str).expect($crate::c_str::EMPTY_C_STR)
+    };
+}
+
+/// Similar to [`non_empty_c_str!`] but for `const` contexts.
+///
+/// Note that the provided expression must be const-evaluatable, else the compilation will fail.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::const_non_empty_c_str;
+///
+/// let interface = const_non_empty_c_str!(c"wlan0");
+/// ```
+///
+/// Failing compilation on empty C strings:
+///
+/// ```compile_fail
+/// use non_empty_str::const_non_empty_c_str;
+///
+/// let never = const_non_empty_c_str!(c"");
+/// ```
+#[macro_export]
+macro_rules! const_non_empty_c_str {
+    ($c_str: expr) => {
+        const { $crate::non_empty_c_str!($c_str) }
+    };
+}
+
+/// Constructs [`NonEmptyAsciiStr`] from the given string, panicking if it is empty or non-ASCII.
+///
+/// # Examples
+///
+/// Simple usage:
+///
+/// ```
+/// use non_empty_str::non_empty_ascii_str;
+///
+/// let header = non_empty_ascii_str!("Content-Type");
+/// ```
+///
+/// Panicking if the string is non-ASCII:
+///
+/// ```should_panic
+/// use non_empty_str::non_empty_ascii_str;
+///
+/// let never = non_empty_ascii_str!("привет");
+/// ```
+///
+/// [`NonEmptyAsciiStr`]: crate::ascii::NonEmptyAsciiStr
+#[macro_export]
+macro_rules! non_empty_ascii_str {
+    ($string: expr) => {
+        $crate::ascii::NonEmptyAsciiStr::from_non_empty_str($crate::non_empty_str!($string))
+            .expect($crate::ascii::NON_ASCII)
+    };
+}
+
+/// Similar to [`non_empty_ascii_str!`] but for `const` contexts.
+///
+/// Note that the provided expression must be const-evaluatable, else the compilation will fail.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::const_non_empty_ascii_str;
+///
+/// let token = const_non_empty_ascii_str!("keep-alive");
+/// ```
+///
+/// Failing compilation on empty strings:
+///
+/// ```compile_fail
+/// use non_empty_str::const_non_empty_ascii_str;
+///
+/// let never = const_non_empty_ascii_str!("");
+/// ```
+///
+/// Failing compilation on non-ASCII strings:
+///
+/// ```compile_fail
+/// use non_empty_str::const_non_empty_ascii_str;
+///
+/// let never = const_non_empty_ascii_str!("naïve");
+/// ```
+#[macro_export]
+macro_rules! const_non_empty_ascii_str {
+    ($string: expr) => {
+        const { $crate::non_empty_ascii_str!($string) }
+    };
+}
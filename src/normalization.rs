@@ -0,0 +1,261 @@
+//! Unicode normalization of non-empty strings.
+
+#[cfg(not(feature = "unicode-normalization"))]
+compile_error!("expected `unicode-normalization` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{borrow::Borrow, string::String};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Borrow, string::String};
+
+use core::{fmt, ops::Deref, str::FromStr};
+
+use unicode_normalization::{UnicodeNormalization, is_nfc, is_nfd, is_nfkc, is_nfkd};
+
+use crate::{
+    str::{EmptyStr, NonEmptyStr},
+    string::{EmptyString, NonEmptyString},
+};
+
+impl NonEmptyStr {
+    /// Returns the Normalization Form C (canonical composition) of this string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let decomposed = non_empty_str!("e\u{301}");
+    ///
+    /// assert_eq!(decomposed.nfc().as_str(), "\u{e9}");
+    /// ```
+    #[must_use]
+    pub fn nfc(&self) -> NonEmptyString {
+        let normalized = self.as_str().nfc().collect();
+
+        // SAFETY: normalizing non-empty string results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(normalized) }
+    }
+
+    /// Returns the Normalization Form D (canonical decomposition) of this string.
+    #[must_use]
+    pub fn nfd(&self) -> NonEmptyString {
+        let normalized = self.as_str().nfd().collect();
+
+        // SAFETY: normalizing non-empty string results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(normalized) }
+    }
+
+    /// Returns the Normalization Form KC (compatibility composition) of this string.
+    #[must_use]
+    pub fn nfkc(&self) -> NonEmptyString {
+        let normalized = self.as_str().nfkc().collect();
+
+        // SAFETY: normalizing non-empty string results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(normalized) }
+    }
+
+    /// Returns the Normalization Form KD (compatibility decomposition) of this string.
+    #[must_use]
+    pub fn nfkd(&self) -> NonEmptyString {
+        let normalized = self.as_str().nfkd().collect();
+
+        // SAFETY: normalizing non-empty string results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(normalized) }
+    }
+
+    /// Checks whether this string is in Normalization Form C.
+    #[must_use]
+    pub fn is_nfc(&self) -> bool {
+        is_nfc(self.as_str())
+    }
+
+    /// Checks whether this string is in Normalization Form D.
+    #[must_use]
+    pub fn is_nfd(&self) -> bool {
+        is_nfd(self.as_str())
+    }
+
+    /// Checks whether this string is in Normalization Form KC.
+    #[must_use]
+    pub fn is_nfkc(&self) -> bool {
+        is_nfkc(self.as_str())
+    }
+
+    /// Checks whether this string is in Normalization Form KD.
+    #[must_use]
+    pub fn is_nfkd(&self) -> bool {
+        is_nfkd(self.as_str())
+    }
+}
+
+/// Represents non-empty strings that are guaranteed to be in Normalization Form C.
+///
+/// Strings are normalized on construction, including deserialization.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::{NormalizedNonEmptyString, non_empty_str};
+///
+/// let composed = NormalizedNonEmptyString::from_non_empty_str(non_empty_str!("\u{e9}"));
+/// let decomposed = NormalizedNonEmptyString::from_non_empty_str(non_empty_str!("e\u{301}"));
+///
+/// assert_eq!(composed, decomposed);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NormalizedNonEmptyString {
+    inner: NonEmptyString,
+}
+
+impl fmt::Display for NormalizedNonEmptyString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl FromStr for NormalizedNonEmptyString {
+    type Err = EmptyStr;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        NonEmptyStr::try_from_str(string).map(Self::from_non_empty_str)
+    }
+}
+
+impl TryFrom<&str> for NormalizedNonEmptyString {
+    type Error = EmptyStr;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl TryFrom<String> for NormalizedNonEmptyString {
+    type Error = EmptyString;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        NonEmptyString::new(string).map(Self::new)
+    }
+}
+
+impl From<&NonEmptyStr> for NormalizedNonEmptyString {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        Self::from_non_empty_str(non_empty)
+    }
+}
+
+impl From<NonEmptyString> for NormalizedNonEmptyString {
+    fn from(non_empty: NonEmptyString) -> Self {
+        Self::new(non_empty)
+    }
+}
+
+impl From<NormalizedNonEmptyString> for NonEmptyString {
+    fn from(normalized: NormalizedNonEmptyString) -> Self {
+        normalized.into_non_empty_string()
+    }
+}
+
+impl From<NormalizedNonEmptyString> for String {
+    fn from(normalized: NormalizedNonEmptyString) -> Self {
+        normalized.into_non_empty_string().into_string()
+    }
+}
+
+impl Borrow<NonEmptyStr> for NormalizedNonEmptyString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl Borrow<str> for NormalizedNonEmptyString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<Self> for NormalizedNonEmptyString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyStr> for NormalizedNonEmptyString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl AsRef<str> for NormalizedNonEmptyString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Deref for NormalizedNonEmptyString {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl NormalizedNonEmptyString {
+    /// Constructs [`Self`] from [`NonEmptyString`], normalizing it to Normalization Form C.
+    ///
+    /// The string is reused if it is already normalized.
+    #[must_use]
+    pub fn new(non_empty: NonEmptyString) -> Self {
+        let inner = if non_empty.is_nfc() {
+            non_empty
+        } else {
+            non_empty.nfc()
+        };
+
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], normalizing it to Normalization Form C.
+    #[must_use]
+    pub fn from_non_empty_str(non_empty: &NonEmptyStr) -> Self {
+        Self {
+            inner: non_empty.nfc(),
+        }
+    }
+
+    /// Constructs [`Self`] without normalizing the [`NonEmptyString`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is in Normalization Form C.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: NonEmptyString) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.inner.as_non_empty_str()
+    }
+
+    /// Returns the contained [`NonEmptyString`].
+    #[must_use]
+    pub const fn as_non_empty_string(&self) -> &NonEmptyString {
+        &self.inner
+    }
+
+    /// Converts [`Self`] into the contained [`NonEmptyString`].
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.inner
+    }
+}
@@ -0,0 +1,545 @@
+//! Non-empty [`OsStr`] and [`OsString`].
+
+#[cfg(not(feature = "std"))]
+compile_error!("expected `std` to be enabled");
+
+use std::{
+    borrow::{Borrow, Cow},
+    ffi::{OsStr, OsString},
+    ops::Deref,
+    path::Path,
+    ptr,
+};
+
+use non_empty_slice::NonEmptyBytes;
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{cow::NonEmptyCowStr, internal::Bytes, str::NonEmptyStr, string::NonEmptyString};
+
+/// The error message used when the OS string is empty.
+pub const EMPTY_OS_STR: &str = "the OS string is empty";
+
+/// Represents errors returned when received OS strings are empty.
+#[derive(Debug, Error)]
+#[error("{EMPTY_OS_STR}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(code(non_empty_str::os), help("make sure the OS string is non-empty"))
+)]
+pub struct EmptyOsStr;
+
+/// The error message used when the owned OS string is empty.
+pub const EMPTY_OS_STRING: &str = "the OS string is empty";
+
+/// Similar to [`EmptyOsStr`], but holds the empty OS string provided.
+#[derive(Debug, Error)]
+#[error("{EMPTY_OS_STRING}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::os::string),
+        help("make sure the OS string is non-empty")
+    )
+)]
+pub struct EmptyOsString {
+    os_string: OsString,
+}
+
+impl EmptyOsString {
+    // NOTE: this is private to prevent creating this error with non-empty OS strings
+    pub(crate) const fn new(os_string: OsString) -> Self {
+        Self { os_string }
+    }
+
+    /// Returns the contained empty OS string.
+    #[must_use]
+    pub fn get(self) -> OsString {
+        self.os_string
+    }
+}
+
+/// Represents non-empty [`OsStr`] values.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyOsStr {
+    inner: OsStr,
+}
+
+impl<'s> TryFrom<&'s OsStr> for &'s NonEmptyOsStr {
+    type Error = EmptyOsStr;
+
+    fn try_from(os_str: &'s OsStr) -> Result<Self, Self::Error> {
+        NonEmptyOsStr::try_from_os_str(os_str)
+    }
+}
+
+impl<'s> From<&'s NonEmptyOsStr> for &'s OsStr {
+    fn from(non_empty: &'s NonEmptyOsStr) -> Self {
+        non_empty.as_os_str()
+    }
+}
+
+impl<'s> From<&'s NonEmptyStr> for &'s NonEmptyOsStr {
+    fn from(non_empty: &'s NonEmptyStr) -> Self {
+        non_empty.as_non_empty_os_str()
+    }
+}
+
+impl AsRef<Self> for NonEmptyOsStr {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<OsStr> for NonEmptyOsStr {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<Path> for NonEmptyOsStr {
+    fn as_ref(&self) -> &Path {
+        self.as_os_str().as_ref()
+    }
+}
+
+impl AsRef<NonEmptyOsStr> for NonEmptyStr {
+    fn as_ref(&self) -> &NonEmptyOsStr {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl AsRef<NonEmptyOsStr> for NonEmptyString {
+    fn as_ref(&self) -> &NonEmptyOsStr {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl Deref for NonEmptyOsStr {
+    type Target = OsStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_os_str()
+    }
+}
+
+impl ToOwned for NonEmptyOsStr {
+    type Owned = NonEmptyOsString;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_empty_os_string()
+    }
+}
+
+impl NonEmptyOsStr {
+    /// Constructs [`Self`] from anything that can be converted to OS string,
+    /// provided it is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyOsStr`] if the OS string is empty.
+    pub fn try_new<S: AsRef<OsStr> + ?Sized>(os_str: &S) -> Result<&Self, EmptyOsStr> {
+        Self::try_from_os_str(os_str.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::NonEmptyOsStr;
+    ///
+    /// let non_empty = NonEmptyOsStr::new("nekit").unwrap();
+    ///
+    /// assert!(NonEmptyOsStr::new("").is_none());
+    /// ```
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<OsStr> + ?Sized>(os_str: &S) -> Option<&Self> {
+        Self::from_os_str(os_str.as_ref())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to OS string,
+    /// without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the OS string is non-empty.
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<OsStr> + ?Sized>(os_str: &S) -> &Self {
+        // SAFETY: the caller must ensure that the OS string is non-empty
+        unsafe { Self::from_os_str_unchecked(os_str.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`OsStr`], provided the OS string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyOsStr`] if the OS string is empty.
+    pub fn try_from_os_str(os_str: &OsStr) -> Result<&Self, EmptyOsStr> {
+        if os_str.is_empty() {
+            return Err(EmptyOsStr);
+        }
+
+        // SAFETY: the OS string is non-empty at this point
+        Ok(unsafe { Self::from_os_str_unchecked(os_str) })
+    }
+
+    /// Similar to [`try_from_os_str`], but the error is discarded.
+    ///
+    /// [`try_from_os_str`]: Self::try_from_os_str
+    #[must_use]
+    pub fn from_os_str(os_str: &OsStr) -> Option<&Self> {
+        if os_str.is_empty() {
+            return None;
+        }
+
+        // SAFETY: the OS string is non-empty at this point
+        Some(unsafe { Self::from_os_str_unchecked(os_str) })
+    }
+
+    /// Constructs [`Self`] from [`OsStr`], without checking if the OS string is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the OS string is non-empty.
+    #[must_use]
+    pub const unsafe fn from_os_str_unchecked(os_str: &OsStr) -> &Self {
+        // SAFETY: the caller must ensure that the OS string is non-empty
+        // `Self` is `repr(transparent)`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(os_str) as *const Self) }
+    }
+
+    /// Returns the contained OS string.
+    #[must_use]
+    pub const fn as_os_str(&self) -> &OsStr {
+        &self.inner
+    }
+
+    /// Checks if the OS string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the OS string is never empty.
+    #[deprecated = "this OS string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the OS string as [`Size`].
+    ///
+    /// Refer to [`OsStr::len`] for more details on what this length represents.
+    #[must_use]
+    pub fn len(&self) -> Size {
+        let len = self.as_os_str().len();
+
+        // SAFETY: the OS string is non-empty by construction, so its length is non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Returns the underlying encoded bytes of the OS string.
+    ///
+    /// Refer to [`OsStr::as_encoded_bytes`] for more details.
+    #[must_use]
+    pub fn as_encoded_bytes(&self) -> &Bytes {
+        self.as_os_str().as_encoded_bytes()
+    }
+
+    /// Returns the underlying encoded bytes of the OS string as [`NonEmptyBytes`].
+    #[must_use]
+    pub fn as_non_empty_encoded_bytes(&self) -> &NonEmptyBytes {
+        // SAFETY: the OS string is non-empty by construction, so are its encoded bytes
+        unsafe { NonEmptyBytes::from_slice_unchecked(self.as_encoded_bytes()) }
+    }
+
+    /// Returns the contained OS string as [`str`], provided it is valid Unicode.
+    #[must_use]
+    pub fn to_str(&self) -> Option<&str> {
+        self.as_os_str().to_str()
+    }
+
+    /// Returns the contained OS string as [`NonEmptyStr`], provided it is valid Unicode.
+    #[must_use]
+    pub fn to_non_empty_str(&self) -> Option<&NonEmptyStr> {
+        self.to_str()
+            // SAFETY: the OS string is non-empty by construction, so is the resulting string
+            .map(|string| unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+
+    /// Converts the OS string to [`NonEmptyCowStr`], including invalid characters.
+    ///
+    /// Any non-Unicode sequences will be replaced with [`char::REPLACEMENT_CHARACTER`].
+    #[must_use]
+    pub fn to_string_lossy(&self) -> NonEmptyCowStr<'_> {
+        match self.as_os_str().to_string_lossy() {
+            // SAFETY: converting non-empty OS string results in non-empty lossy string
+            Cow::Owned(string) => Cow::Owned(unsafe { NonEmptyString::new_unchecked(string) }),
+            Cow::Borrowed(string) => {
+                // SAFETY: converting non-empty OS string results in non-empty lossy string
+                Cow::Borrowed(unsafe { NonEmptyStr::from_str_unchecked(string) })
+            }
+        }
+    }
+
+    /// Converts [`Self`] to [`NonEmptyOsString`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_os_string(&self) -> NonEmptyOsString {
+        NonEmptyOsString::from_non_empty_os_str(self)
+    }
+}
+
+impl NonEmptyStr {
+    /// Converts [`Self`] to [`NonEmptyOsStr`].
+    #[must_use]
+    pub fn as_non_empty_os_str(&self) -> &NonEmptyOsStr {
+        // SAFETY: the string is non-empty by construction, so is the OS string
+        unsafe { NonEmptyOsStr::from_os_str_unchecked(self.as_str().as_ref()) }
+    }
+
+    /// Converts [`Self`] to [`NonEmptyOsString`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_os_string(&self) -> NonEmptyOsString {
+        self.as_non_empty_os_str().to_non_empty_os_string()
+    }
+}
+
+/// Represents non-empty [`OsString`] values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyOsString {
+    inner: OsString,
+}
+
+impl Borrow<NonEmptyOsStr> for NonEmptyOsString {
+    fn borrow(&self) -> &NonEmptyOsStr {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl Borrow<OsStr> for NonEmptyOsString {
+    fn borrow(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl TryFrom<OsString> for NonEmptyOsString {
+    type Error = EmptyOsString;
+
+    fn try_from(os_string: OsString) -> Result<Self, Self::Error> {
+        Self::new(os_string)
+    }
+}
+
+impl TryFrom<&OsStr> for NonEmptyOsString {
+    type Error = EmptyOsStr;
+
+    fn try_from(os_str: &OsStr) -> Result<Self, Self::Error> {
+        let non_empty: &NonEmptyOsStr = os_str.try_into()?;
+
+        Ok(non_empty.into())
+    }
+}
+
+impl From<NonEmptyOsString> for OsString {
+    fn from(non_empty: NonEmptyOsString) -> Self {
+        non_empty.into_os_string()
+    }
+}
+
+impl From<&NonEmptyOsStr> for NonEmptyOsString {
+    fn from(non_empty: &NonEmptyOsStr) -> Self {
+        non_empty.to_non_empty_os_string()
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyOsString {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        non_empty.to_non_empty_os_string()
+    }
+}
+
+impl From<NonEmptyString> for NonEmptyOsString {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_non_empty_os_string()
+    }
+}
+
+impl TryFrom<NonEmptyOsString> for NonEmptyString {
+    type Error = NonEmptyOsString;
+
+    fn try_from(non_empty: NonEmptyOsString) -> Result<Self, Self::Error> {
+        non_empty.into_non_empty_string()
+    }
+}
+
+impl AsRef<Self> for NonEmptyOsString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyOsStr> for NonEmptyOsString {
+    fn as_ref(&self) -> &NonEmptyOsStr {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl AsRef<OsStr> for NonEmptyOsString {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<Path> for NonEmptyOsString {
+    fn as_ref(&self) -> &Path {
+        self.as_os_str().as_ref()
+    }
+}
+
+impl Deref for NonEmptyOsString {
+    type Target = NonEmptyOsStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl<'s> Extend<&'s OsStr> for NonEmptyOsString {
+    fn extend<I: IntoIterator<Item = &'s OsStr>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the OS string empty
+        unsafe {
+            self.as_mut_os_string().extend(iterable);
+        }
+    }
+}
+
+impl<'s> Extend<&'s NonEmptyOsStr> for NonEmptyOsString {
+    fn extend<I: IntoIterator<Item = &'s NonEmptyOsStr>>(&mut self, iterable: I) {
+        self.extend(iterable.into_iter().map(NonEmptyOsStr::as_os_str));
+    }
+}
+
+impl Extend<OsString> for NonEmptyOsString {
+    fn extend<I: IntoIterator<Item = OsString>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the OS string empty
+        unsafe {
+            self.as_mut_os_string().extend(iterable);
+        }
+    }
+}
+
+impl Extend<Self> for NonEmptyOsString {
+    fn extend<I: IntoIterator<Item = Self>>(&mut self, iterable: I) {
+        self.extend(iterable.into_iter().map(Self::into_os_string));
+    }
+}
+
+impl NonEmptyOsString {
+    /// Constructs [`Self`], provided that the [`OsString`] is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyOsString`] if the OS string is empty.
+    pub fn new(os_string: OsString) -> Result<Self, EmptyOsString> {
+        if os_string.is_empty() {
+            return Err(EmptyOsString::new(os_string));
+        }
+
+        // SAFETY: the OS string is non-empty at this point
+        Ok(unsafe { Self::new_unchecked(os_string) })
+    }
+
+    /// Constructs [`Self`] without checking if the OS string is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the OS string is non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: OsString) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyOsStr`] via cloning.
+    #[must_use]
+    pub fn from_non_empty_os_str(non_empty: &NonEmptyOsStr) -> Self {
+        // SAFETY: the OS string is non-empty by construction
+        unsafe { Self::new_unchecked(non_empty.as_os_str().to_os_string()) }
+    }
+
+    /// Returns the contained OS string reference.
+    #[must_use]
+    pub const fn as_os_string(&self) -> &OsString {
+        &self.inner
+    }
+
+    /// Returns the contained mutable OS string reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the OS string remains non-empty.
+    #[must_use]
+    pub const unsafe fn as_mut_os_string(&mut self) -> &mut OsString {
+        &mut self.inner
+    }
+
+    /// Returns the contained [`OsString`].
+    #[must_use]
+    pub fn into_os_string(self) -> OsString {
+        self.inner
+    }
+
+    /// Extracts the OS string slice containing the entire OS string.
+    #[must_use]
+    pub fn as_os_str(&self) -> &OsStr {
+        self.as_os_string().as_os_str()
+    }
+
+    /// Returns contained OS string reference as [`NonEmptyOsStr`].
+    #[must_use]
+    pub fn as_non_empty_os_str(&self) -> &NonEmptyOsStr {
+        // SAFETY: the OS string is non-empty by construction
+        unsafe { NonEmptyOsStr::from_os_str_unchecked(self.as_os_str()) }
+    }
+
+    /// Converts [`Self`] into [`NonEmptyString`], provided it is valid Unicode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the original OS string if it is not valid Unicode.
+    pub fn into_non_empty_string(self) -> Result<NonEmptyString, Self> {
+        self.into_os_string()
+            .into_string()
+            // SAFETY: the OS string is non-empty by construction, so is the resulting string
+            .map(|string| unsafe { NonEmptyString::new_unchecked(string) })
+            // SAFETY: reclaiming ownership of previously passed non-empty OS string is safe
+            .map_err(|os_string| unsafe { Self::new_unchecked(os_string) })
+    }
+
+    /// Appends anything that can be converted to OS string onto the end of this OS string.
+    pub fn push<S: AsRef<OsStr>>(&mut self, os_str: S) {
+        // SAFETY: pushing can not make the OS string empty
+        unsafe {
+            self.as_mut_os_string().push(os_str);
+        }
+    }
+
+    /// Returns the capacity of the OS string as [`Size`].
+    #[must_use]
+    pub fn capacity(&self) -> Size {
+        let capacity = self.as_os_string().capacity();
+
+        // SAFETY: capacity is always non-zero for non-empty OS strings
+        unsafe { Size::new_unchecked(capacity) }
+    }
+}
+
+impl NonEmptyString {
+    /// Converts [`Self`] into [`NonEmptyOsString`].
+    #[must_use]
+    pub fn into_non_empty_os_string(self) -> NonEmptyOsString {
+        // SAFETY: the string is non-empty by construction, so is the resulting OS string
+        unsafe { NonEmptyOsString::new_unchecked(self.into_string().into()) }
+    }
+}
@@ -2,13 +2,67 @@
 compile_error!("expected `ownership` to be enabled");
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-use ownership::impl_identity;
+use ownership::{IntoOwned, impl_identity};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-use crate::{boxed::NonEmptyBoxedStr, string::NonEmptyString};
+use crate::{
+    ascii::NonEmptyAsciiString, blank::NonBlankString, bounded::BoundedString,
+    boxed::NonEmptyBoxedStr, c_string::NonEmptyCString, small_string::NonEmptySmallString,
+    string::NonEmptyString, validated::ValidatedString,
+};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl_identity!(NonEmptyBoxedStr);
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl_identity!(NonEmptyString);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_identity!(NonEmptySmallString);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_identity!(NonBlankString);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_identity!(NonEmptyAsciiString);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_identity!(NonEmptyCString);
+
+// NOTE: `impl_identity!` does not support generic types, hence the manual implementation
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> IntoOwned for BoundedString<MIN, MAX, M> {
+    type Owned = Self;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> IntoOwned for ValidatedString<V> {
+    type Owned = Self;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::{os::NonEmptyOsString, path::NonEmptyPathBuf};
+
+#[cfg(feature = "std")]
+impl_identity!(NonEmptyOsString);
+
+#[cfg(feature = "std")]
+impl_identity!(NonEmptyPathBuf);
+
+#[cfg(feature = "unicode-normalization")]
+use crate::normalization::NormalizedNonEmptyString;
+
+#[cfg(feature = "unicode-normalization")]
+impl_identity!(NormalizedNonEmptyString);
+
+// NOTE: `IntoOwned` can not be implemented for `NonEmptyRcStr` and `NonEmptyArcStr`
+// due to orphan rules, as neither `Rc` nor `Arc` are local to this crate
@@ -0,0 +1,613 @@
+//! Non-empty [`Path`] and [`PathBuf`].
+
+#[cfg(not(feature = "std"))]
+compile_error!("expected `std` to be enabled");
+
+use std::{
+    borrow::Borrow,
+    ffi::OsStr,
+    ops::Deref,
+    path::{Path, PathBuf},
+    ptr,
+};
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{
+    cow::NonEmptyCowStr,
+    os::{NonEmptyOsStr, NonEmptyOsString},
+    str::NonEmptyStr,
+    string::NonEmptyString,
+};
+
+/// The error message used when the path is empty.
+pub const EMPTY_PATH: &str = "the path is empty";
+
+/// Represents errors returned when received paths are empty.
+#[derive(Debug, Error)]
+#[error("{EMPTY_PATH}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(code(non_empty_str::path), help("make sure the path is non-empty"))
+)]
+pub struct EmptyPath;
+
+/// The error message used when the path buffer is empty.
+pub const EMPTY_PATH_BUF: &str = "the path is empty";
+
+/// Similar to [`EmptyPath`], but holds the empty path buffer provided.
+#[derive(Debug, Error)]
+#[error("{EMPTY_PATH_BUF}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::path::buf),
+        help("make sure the path is non-empty")
+    )
+)]
+pub struct EmptyPathBuf {
+    path_buf: PathBuf,
+}
+
+impl EmptyPathBuf {
+    // NOTE: this is private to prevent creating this error with non-empty paths
+    pub(crate) const fn new(path_buf: PathBuf) -> Self {
+        Self { path_buf }
+    }
+
+    /// Returns the contained empty path buffer.
+    #[must_use]
+    pub fn get(self) -> PathBuf {
+        self.path_buf
+    }
+}
+
+/// Represents non-empty [`Path`] values.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyPath {
+    inner: Path,
+}
+
+impl<'p> TryFrom<&'p Path> for &'p NonEmptyPath {
+    type Error = EmptyPath;
+
+    fn try_from(path: &'p Path) -> Result<Self, Self::Error> {
+        NonEmptyPath::try_from_path(path)
+    }
+}
+
+impl<'p> From<&'p NonEmptyPath> for &'p Path {
+    fn from(non_empty: &'p NonEmptyPath) -> Self {
+        non_empty.as_path()
+    }
+}
+
+impl<'s> From<&'s NonEmptyStr> for &'s NonEmptyPath {
+    fn from(non_empty: &'s NonEmptyStr) -> Self {
+        non_empty.as_non_empty_path()
+    }
+}
+
+impl<'s> From<&'s NonEmptyOsStr> for &'s NonEmptyPath {
+    fn from(non_empty: &'s NonEmptyOsStr) -> Self {
+        non_empty.as_non_empty_path()
+    }
+}
+
+impl AsRef<Self> for NonEmptyPath {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<Path> for NonEmptyPath {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<OsStr> for NonEmptyPath {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<NonEmptyOsStr> for NonEmptyPath {
+    fn as_ref(&self) -> &NonEmptyOsStr {
+        self.as_non_empty_os_str()
+    }
+}
+
+impl AsRef<NonEmptyPath> for NonEmptyStr {
+    fn as_ref(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl AsRef<NonEmptyPath> for NonEmptyString {
+    fn as_ref(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl AsRef<NonEmptyPath> for NonEmptyOsStr {
+    fn as_ref(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl AsRef<NonEmptyPath> for NonEmptyOsString {
+    fn as_ref(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl Deref for NonEmptyPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl ToOwned for NonEmptyPath {
+    type Owned = NonEmptyPathBuf;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_empty_path_buf()
+    }
+}
+
+impl NonEmptyPath {
+    /// Constructs [`Self`] from anything that can be converted to path, provided it is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyPath`] if the path is empty.
+    pub fn try_new<P: AsRef<Path> + ?Sized>(path: &P) -> Result<&Self, EmptyPath> {
+        Self::try_from_path(path.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::NonEmptyPath;
+    ///
+    /// let non_empty = NonEmptyPath::new("src/lib.rs").unwrap();
+    ///
+    /// assert!(NonEmptyPath::new("").is_none());
+    /// ```
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<P: AsRef<Path> + ?Sized>(path: &P) -> Option<&Self> {
+        Self::from_path(path.as_ref())
+    }
+
+    /// Constructs [`Self`] from anything that can be converted to path, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the path is non-empty.
+    #[must_use]
+    pub unsafe fn new_unchecked<P: AsRef<Path> + ?Sized>(path: &P) -> &Self {
+        // SAFETY: the caller must ensure that the path is non-empty
+        unsafe { Self::from_path_unchecked(path.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`Path`], provided the path is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyPath`] if the path is empty.
+    pub fn try_from_path(path: &Path) -> Result<&Self, EmptyPath> {
+        if path.as_os_str().is_empty() {
+            return Err(EmptyPath);
+        }
+
+        // SAFETY: the path is non-empty at this point
+        Ok(unsafe { Self::from_path_unchecked(path) })
+    }
+
+    /// Similar to [`try_from_path`], but the error is discarded.
+    ///
+    /// [`try_from_path`]: Self::try_from_path
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<&Self> {
+        if path.as_os_str().is_empty() {
+            return None;
+        }
+
+        // SAFETY: the path is non-empty at this point
+        Some(unsafe { Self::from_path_unchecked(path) })
+    }
+
+    /// Constructs [`Self`] from [`Path`], without checking if the path is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the path is non-empty.
+    #[must_use]
+    pub const unsafe fn from_path_unchecked(path: &Path) -> &Self {
+        // SAFETY: the caller must ensure that the path is non-empty
+        // `Self` is `repr(transparent)`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(path) as *const Self) }
+    }
+
+    /// Returns the contained path.
+    #[must_use]
+    pub const fn as_path(&self) -> &Path {
+        &self.inner
+    }
+
+    /// Checks if the path is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the path is never empty.
+    #[deprecated = "this path is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the path as [`Size`].
+    ///
+    /// Refer to [`OsStr::len`] for more details on what this length represents.
+    #[must_use]
+    pub fn len(&self) -> Size {
+        self.as_non_empty_os_str().len()
+    }
+
+    /// Returns the underlying OS string of the path.
+    #[must_use]
+    pub fn as_os_str(&self) -> &OsStr {
+        self.as_path().as_os_str()
+    }
+
+    /// Returns the underlying OS string of the path as [`NonEmptyOsStr`].
+    #[must_use]
+    pub fn as_non_empty_os_str(&self) -> &NonEmptyOsStr {
+        // SAFETY: the path is non-empty by construction, so is its OS string
+        unsafe { NonEmptyOsStr::from_os_str_unchecked(self.as_os_str()) }
+    }
+
+    /// Returns the contained path as [`NonEmptyStr`], provided it is valid Unicode.
+    #[must_use]
+    pub fn to_non_empty_str(&self) -> Option<&NonEmptyStr> {
+        self.as_non_empty_os_str().to_non_empty_str()
+    }
+
+    /// Converts the path to [`NonEmptyCowStr`], including invalid characters.
+    ///
+    /// Any non-Unicode sequences will be replaced with [`char::REPLACEMENT_CHARACTER`].
+    #[must_use]
+    pub fn to_string_lossy(&self) -> NonEmptyCowStr<'_> {
+        self.as_non_empty_os_str().to_string_lossy()
+    }
+
+    /// Converts [`Self`] to [`NonEmptyPathBuf`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_path_buf(&self) -> NonEmptyPathBuf {
+        NonEmptyPathBuf::from_non_empty_path(self)
+    }
+
+    /// Returns the parent of this path, provided it exists and is non-empty.
+    ///
+    /// Unlike [`Path::parent`], this method returns [`None`] for relative paths
+    /// consisting of one component, as their parent is the empty path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use non_empty_str::NonEmptyPath;
+    ///
+    /// let path = NonEmptyPath::new("src/lib.rs").unwrap();
+    ///
+    /// let parent = path.parent().unwrap();
+    ///
+    /// assert_eq!(parent.as_path(), Path::new("src"));
+    ///
+    /// assert!(parent.parent().is_none());
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<&Self> {
+        self.as_path().parent().and_then(Self::from_path)
+    }
+
+    /// Returns the final component of this path as [`NonEmptyOsStr`], if there is one.
+    #[must_use]
+    pub fn file_name(&self) -> Option<&NonEmptyOsStr> {
+        self.as_path()
+            .file_name()
+            .and_then(NonEmptyOsStr::from_os_str)
+    }
+
+    /// Returns the stem of the final component of this path as [`NonEmptyOsStr`],
+    /// if there is one.
+    #[must_use]
+    pub fn file_stem(&self) -> Option<&NonEmptyOsStr> {
+        self.as_path()
+            .file_stem()
+            .and_then(NonEmptyOsStr::from_os_str)
+    }
+
+    /// Returns the extension of the final component of this path as [`NonEmptyOsStr`],
+    /// provided it exists and is non-empty.
+    #[must_use]
+    pub fn extension(&self) -> Option<&NonEmptyOsStr> {
+        self.as_path()
+            .extension()
+            .and_then(NonEmptyOsStr::from_os_str)
+    }
+
+    /// Creates [`NonEmptyPathBuf`] with the given path adjoined to this one.
+    ///
+    /// Refer to [`PathBuf::push`] for more details on how the path is adjoined.
+    ///
+    /// The resulting path is always non-empty, since it either contains this path as prefix,
+    /// or is replaced by the given absolute path, which is non-empty.
+    #[must_use]
+    pub fn join<P: AsRef<Path>>(&self, path: P) -> NonEmptyPathBuf {
+        let joined = self.as_path().join(path);
+
+        // SAFETY: joining to non-empty path always results in non-empty path
+        unsafe { NonEmptyPathBuf::new_unchecked(joined) }
+    }
+}
+
+impl NonEmptyStr {
+    /// Converts [`Self`] to [`NonEmptyPath`].
+    #[must_use]
+    pub fn as_non_empty_path(&self) -> &NonEmptyPath {
+        self.as_non_empty_os_str().as_non_empty_path()
+    }
+}
+
+impl NonEmptyOsStr {
+    /// Converts [`Self`] to [`NonEmptyPath`].
+    #[must_use]
+    pub fn as_non_empty_path(&self) -> &NonEmptyPath {
+        // SAFETY: the OS string is non-empty by construction, so is the path
+        unsafe { NonEmptyPath::from_path_unchecked(self.as_os_str().as_ref()) }
+    }
+}
+
+/// Represents non-empty [`PathBuf`] values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyPathBuf {
+    inner: PathBuf,
+}
+
+impl Borrow<NonEmptyPath> for NonEmptyPathBuf {
+    fn borrow(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl Borrow<Path> for NonEmptyPathBuf {
+    fn borrow(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl TryFrom<PathBuf> for NonEmptyPathBuf {
+    type Error = EmptyPathBuf;
+
+    fn try_from(path_buf: PathBuf) -> Result<Self, Self::Error> {
+        Self::new(path_buf)
+    }
+}
+
+impl TryFrom<&Path> for NonEmptyPathBuf {
+    type Error = EmptyPath;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let non_empty: &NonEmptyPath = path.try_into()?;
+
+        Ok(non_empty.into())
+    }
+}
+
+impl From<NonEmptyPathBuf> for PathBuf {
+    fn from(non_empty: NonEmptyPathBuf) -> Self {
+        non_empty.into_path_buf()
+    }
+}
+
+impl From<&NonEmptyPath> for NonEmptyPathBuf {
+    fn from(non_empty: &NonEmptyPath) -> Self {
+        non_empty.to_non_empty_path_buf()
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyPathBuf {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        non_empty.as_non_empty_path().to_non_empty_path_buf()
+    }
+}
+
+impl From<NonEmptyString> for NonEmptyPathBuf {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_non_empty_os_string().into()
+    }
+}
+
+impl From<&NonEmptyOsStr> for NonEmptyPathBuf {
+    fn from(non_empty: &NonEmptyOsStr) -> Self {
+        non_empty.as_non_empty_path().to_non_empty_path_buf()
+    }
+}
+
+impl From<NonEmptyOsString> for NonEmptyPathBuf {
+    fn from(non_empty: NonEmptyOsString) -> Self {
+        // SAFETY: the OS string is non-empty by construction, so is the resulting path
+        unsafe { Self::new_unchecked(non_empty.into_os_string().into()) }
+    }
+}
+
+impl From<NonEmptyPathBuf> for NonEmptyOsString {
+    fn from(non_empty: NonEmptyPathBuf) -> Self {
+        non_empty.into_non_empty_os_string()
+    }
+}
+
+impl AsRef<Self> for NonEmptyPathBuf {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyPath> for NonEmptyPathBuf {
+    fn as_ref(&self) -> &NonEmptyPath {
+        self.as_non_empty_path()
+    }
+}
+
+impl AsRef<Path> for NonEmptyPathBuf {
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<OsStr> for NonEmptyPathBuf {
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl Deref for NonEmptyPathBuf {
+    type Target = NonEmptyPath;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_path()
+    }
+}
+
+impl NonEmptyPathBuf {
+    /// Constructs [`Self`], provided that the [`PathBuf`] is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyPathBuf`] if the path is empty.
+    pub fn new(path_buf: PathBuf) -> Result<Self, EmptyPathBuf> {
+        if path_buf.as_os_str().is_empty() {
+            return Err(EmptyPathBuf::new(path_buf));
+        }
+
+        // SAFETY: the path is non-empty at this point
+        Ok(unsafe { Self::new_unchecked(path_buf) })
+    }
+
+    /// Constructs [`Self`] without checking if the path is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the path is non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: PathBuf) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyPath`] via cloning.
+    #[must_use]
+    pub fn from_non_empty_path(non_empty: &NonEmptyPath) -> Self {
+        // SAFETY: the path is non-empty by construction
+        unsafe { Self::new_unchecked(non_empty.as_path().to_path_buf()) }
+    }
+
+    /// Returns the contained path buffer reference.
+    #[must_use]
+    pub const fn as_path_buf(&self) -> &PathBuf {
+        &self.inner
+    }
+
+    /// Returns the contained mutable path buffer reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the path remains non-empty.
+    #[must_use]
+    pub const unsafe fn as_mut_path_buf(&mut self) -> &mut PathBuf {
+        &mut self.inner
+    }
+
+    /// Returns the contained [`PathBuf`].
+    #[must_use]
+    pub fn into_path_buf(self) -> PathBuf {
+        self.inner
+    }
+
+    /// Converts [`Self`] into [`NonEmptyOsString`].
+    #[must_use]
+    pub fn into_non_empty_os_string(self) -> NonEmptyOsString {
+        // SAFETY: the path is non-empty by construction, so is the resulting OS string
+        unsafe { NonEmptyOsString::new_unchecked(self.into_path_buf().into_os_string()) }
+    }
+
+    /// Extracts the path slice containing the entire path.
+    #[must_use]
+    pub fn as_path(&self) -> &Path {
+        self.as_path_buf().as_path()
+    }
+
+    /// Returns contained path reference as [`NonEmptyPath`].
+    #[must_use]
+    pub fn as_non_empty_path(&self) -> &NonEmptyPath {
+        // SAFETY: the path is non-empty by construction
+        unsafe { NonEmptyPath::from_path_unchecked(self.as_path()) }
+    }
+
+    /// Extends this path with the given one.
+    ///
+    /// Refer to [`PathBuf::push`] for more details on how the path is adjoined.
+    pub fn push<P: AsRef<Path>>(&mut self, path: P) {
+        // SAFETY: pushing to non-empty path can not make the path empty
+        unsafe {
+            self.as_mut_path_buf().push(path);
+        }
+    }
+
+    /// Truncates this path to its parent, provided the parent is non-empty.
+    ///
+    /// Returns [`false`] and does nothing if there is no parent or the parent is empty.
+    pub fn pop(&mut self) -> bool {
+        if self.as_non_empty_path().parent().is_none() {
+            return false;
+        }
+
+        // SAFETY: popping only when the parent is non-empty
+        unsafe { self.as_mut_path_buf().pop() }
+    }
+
+    /// Returns the capacity of the path as [`Size`].
+    #[must_use]
+    pub fn capacity(&self) -> Size {
+        let capacity = self.as_path_buf().capacity();
+
+        // SAFETY: capacity is always non-zero for non-empty paths
+        unsafe { Size::new_unchecked(capacity) }
+    }
+}
+
+impl NonEmptyString {
+    /// Converts [`Self`] into [`NonEmptyPathBuf`].
+    #[must_use]
+    pub fn into_non_empty_path_buf(self) -> NonEmptyPathBuf {
+        self.into()
+    }
+}
+
+impl NonEmptyOsString {
+    /// Converts [`Self`] into [`NonEmptyPathBuf`].
+    #[must_use]
+    pub fn into_non_empty_path_buf(self) -> NonEmptyPathBuf {
+        self.into()
+    }
+}
@@ -0,0 +1,123 @@
+//! Property-based testing strategies for non-empty strings.
+
+#[cfg(not(feature = "proptest"))]
+compile_error!("expected `proptest` to be enabled");
+
+use std::string::String;
+
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    collection::{SizeRange, vec},
+    strategy::{BoxedStrategy, Strategy},
+    string::{Error, string_regex},
+};
+
+use crate::{boxed::NonEmptyBoxedStr, string::NonEmptyString};
+
+/// The message used when rejecting empty strings generated from regular expressions.
+pub const REJECT_EMPTY: &str = "generated string is empty";
+
+/// The message used for panics when the given size range does not contain any positive sizes.
+pub const EMPTY_SIZE_RANGE: &str = "expected size range to contain positive sizes";
+
+/// The regular expression used to generate non-empty strings by default.
+pub const DEFAULT_REGEX: &str = "\\PC+";
+
+/// Creates strategies that generate non-empty strings matching the given regular expression.
+///
+/// Any empty strings that the regular expression matches are rejected.
+///
+/// # Errors
+///
+/// Returns [`Error`] if the regular expression is invalid or not supported by [`proptest`].
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::proptest::non_empty_string_regex;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let strategy = non_empty_string_regex("[a-z]*").unwrap();
+///
+/// let mut runner = TestRunner::deterministic();
+///
+/// let string = strategy.new_tree(&mut runner).unwrap().current();
+///
+/// assert!(string.as_str().chars().all(|character| character.is_ascii_lowercase()));
+/// ```
+// NOTE: the error type is defined by `proptest` and is therefore returned as-is
+#[allow(clippy::result_large_err)]
+pub fn non_empty_string_regex(
+    regex: &str,
+) -> Result<impl Strategy<Value = NonEmptyString> + use<>, Error> {
+    let strategy = string_regex(regex)?
+        .prop_filter_map(REJECT_EMPTY, |string| NonEmptyString::new(string).ok());
+
+    Ok(strategy)
+}
+
+/// Creates strategies that generate non-empty strings of characters from the given strategy,
+/// with the number of characters in the given size range.
+///
+/// Size ranges are clamped to start at `1`, so empty strings are never generated.
+///
+/// # Panics
+///
+/// Panics if the given size range does not contain any positive sizes.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::proptest::non_empty_string_of;
+/// use proptest::char::range;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let strategy = non_empty_string_of(range('a', 'z'), ..=8);
+///
+/// let mut runner = TestRunner::deterministic();
+///
+/// let string = strategy.new_tree(&mut runner).unwrap().current();
+///
+/// assert!(string.char_count().get() <= 8);
+/// ```
+pub fn non_empty_string_of<S: Strategy<Value = char>, R: Into<SizeRange>>(
+    characters: S,
+    size: R,
+) -> impl Strategy<Value = NonEmptyString> {
+    let (start, end) = size.into().start_end_incl();
+
+    assert!(end > 0, "{EMPTY_SIZE_RANGE}");
+
+    let size = SizeRange::new(start.max(1)..=end);
+
+    vec(characters, size).prop_map(|characters| {
+        let string: String = characters.into_iter().collect();
+
+        // SAFETY: the size range starts at `1`, so the string contains at least one character
+        unsafe { NonEmptyString::new_unchecked(string) }
+    })
+}
+
+impl Arbitrary for NonEmptyString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        non_empty_string_regex(DEFAULT_REGEX)
+            .expect("default regex is valid")
+            .boxed()
+    }
+}
+
+impl Arbitrary for NonEmptyBoxedStr {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<NonEmptyString>()
+            .prop_map(NonEmptyString::into_non_empty_boxed_str)
+            .boxed()
+    }
+}
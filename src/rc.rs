@@ -0,0 +1,163 @@
+//! Non-empty [`Rc<str>`].
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{rc::Rc, string::String};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{rc::Rc, string::String};
+
+use non_empty_iter::{FromNonEmptyIterator, IntoNonEmptyIterator};
+use thiserror::Error;
+
+use crate::{
+    boxed::NonEmptyBoxedStr,
+    str::NonEmptyStr,
+    string::{EmptyString, NonEmptyString},
+};
+
+/// The error message used when the reference-counted string is empty.
+pub const EMPTY_RC_STR: &str = "the reference-counted string is empty";
+
+/// Similar to [`EmptyBoxedStr`], but contains the empty reference-counted string provided.
+///
+/// [`EmptyBoxedStr`]: crate::boxed::EmptyBoxedStr
+#[derive(Debug, Error)]
+#[error("{EMPTY_RC_STR}")]
+pub struct EmptyRcStr {
+    rc: Rc<str>,
+}
+
+impl EmptyRcStr {
+    // NOTE: this is private to prevent creating this error with non-empty strings
+    pub(crate) const fn new(rc: Rc<str>) -> Self {
+        Self { rc }
+    }
+
+    /// Returns the contained empty reference-counted string.
+    #[must_use]
+    pub fn get(self) -> Rc<str> {
+        self.rc
+    }
+
+    /// Constructs [`Self`] from [`EmptyString`].
+    #[must_use]
+    pub fn from_empty_string(empty: EmptyString) -> Self {
+        Self::new(Rc::from(empty.get()))
+    }
+
+    /// Converts [`Self`] into [`EmptyString`].
+    #[must_use]
+    pub fn into_empty_string(self) -> EmptyString {
+        EmptyString::new(String::from(&*self.rc))
+    }
+}
+
+/// Represents non-empty reference-counted strings, [`Rc<NonEmptyStr>`].
+///
+/// Serialization and deserialization via `serde` require the `serde-rc` feature.
+pub type NonEmptyRcStr = Rc<NonEmptyStr>;
+
+// NOTE: `TryFrom<Rc<str>>` can not be implemented for `NonEmptyRcStr` due to orphan rules,
+// which is why `NonEmptyStr::from_rc_str` should be used instead
+
+impl From<NonEmptyString> for NonEmptyRcStr {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_non_empty_rc_str()
+    }
+}
+
+// NOTE: `From<Box<T>>` is implemented for `Rc<T>`, therefore `NonEmptyRcStr`
+// can be constructed from `NonEmptyBoxedStr` without any additional implementations
+
+impl From<&NonEmptyStr> for NonEmptyRcStr {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        non_empty.to_non_empty_rc_str()
+    }
+}
+
+// NOTE: `From<NonEmptyCowStr<'_>>` is implemented for `Rc<B>`, provided `Rc<B>` is both
+// `From<&B>` and `From<B::Owned>`, therefore `NonEmptyRcStr` has it as well
+
+impl From<NonEmptyRcStr> for NonEmptyString {
+    fn from(non_empty: NonEmptyRcStr) -> Self {
+        Self::from_non_empty_str(&non_empty)
+    }
+}
+
+impl NonEmptyStr {
+    /// Constructs [`Self`] from [`Rc<str>`], provided the reference-counted string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyRcStr`] if the reference-counted string is empty.
+    pub fn from_rc_str(rc: Rc<str>) -> Result<Rc<Self>, EmptyRcStr> {
+        if rc.is_empty() {
+            return Err(EmptyRcStr::new(rc));
+        }
+
+        // SAFETY: the reference-counted string is non-empty at this point
+        Ok(unsafe { Self::from_rc_str_unchecked(rc) })
+    }
+
+    /// Constructs [`Self`] from [`Rc<str>`] without checking
+    /// if the reference-counted string is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the reference-counted string is non-empty.
+    #[must_use]
+    pub unsafe fn from_rc_str_unchecked(rc: Rc<str>) -> Rc<Self> {
+        // SAFETY: the caller must ensure that the reference-counted string is non-empty
+        // moreover, `Self` is `repr(transparent)`, so it is safe to transmute
+        // finally, `Rc` is created from the raw pointer existing within this function only
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const Self) }
+    }
+
+    /// Converts [`Self`] into [`Rc<str>`].
+    #[must_use]
+    pub fn into_rc_str(self: Rc<Self>) -> Rc<str> {
+        // SAFETY: `Self` is `repr(transparent)`, so it is safe to transmute
+        // moreover, `Rc` is created from the raw pointer existing within this function only
+        unsafe { Rc::from_raw(Rc::into_raw(self) as *const str) }
+    }
+
+    /// Converts [`Self`] to [`NonEmptyRcStr`] via cloning.
+    #[must_use]
+    pub fn to_non_empty_rc_str(&self) -> NonEmptyRcStr {
+        // SAFETY: the string is non-empty by construction, so is the resulting one
+        unsafe { Self::from_rc_str_unchecked(self.as_str().into()) }
+    }
+}
+
+impl NonEmptyString {
+    /// Converts [`Self`] into [`NonEmptyRcStr`].
+    #[must_use]
+    pub fn into_non_empty_rc_str(self) -> NonEmptyRcStr {
+        // SAFETY: the string is non-empty by construction, so is the resulting one
+        unsafe { NonEmptyStr::from_rc_str_unchecked(self.into_string().into()) }
+    }
+}
+
+// NOTE: `FromNonEmptyIterator<T>` can only be implemented for `NonEmptyRcStr`
+// when `T` is local due to orphan rules
+
+impl<'s> FromNonEmptyIterator<&'s NonEmptyStr> for NonEmptyRcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = &'s NonEmptyStr>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_rc_str()
+    }
+}
+
+impl FromNonEmptyIterator<NonEmptyString> for NonEmptyRcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = NonEmptyString>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_rc_str()
+    }
+}
+
+impl FromNonEmptyIterator<NonEmptyBoxedStr> for NonEmptyRcStr {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = NonEmptyBoxedStr>>(iterable: I) -> Self {
+        NonEmptyString::from_non_empty_iter(iterable).into_non_empty_rc_str()
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(not(feature = "rusqlite"))]
+compile_error!("expected `rusqlite` to be enabled");
+
+use std::boxed::Box;
+
+use rusqlite::{
+    Result,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef},
+};
+
+use crate::{boxed::NonEmptyBoxedStr, str::NonEmptyStr, string::NonEmptyString};
+
+// NOTE: `rusqlite` implements `ToSql` for boxed, rc and arc values of types implementing it,
+// so those are covered by the implementation for `NonEmptyStr`
+
+impl ToSql for NonEmptyStr {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        self.as_str().to_sql()
+    }
+}
+
+impl ToSql for NonEmptyString {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        self.as_str().to_sql()
+    }
+}
+
+impl FromSql for NonEmptyString {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(_) => {
+                let string = value.as_str()?.to_owned();
+
+                Self::new(string).map_err(|error| FromSqlError::Other(Box::new(error)))
+            }
+            ValueRef::Blob(bytes) => Self::from_utf8(bytes.to_owned())
+                .map_err(|error| FromSqlError::Other(Box::new(error))),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl FromSql for NonEmptyBoxedStr {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        NonEmptyString::column_result(value).map(NonEmptyString::into_non_empty_boxed_str)
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(not(feature = "schemars"))]
+compile_error!("expected `schemars` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, format};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, format};
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::{
+    array_string::NonEmptyArrayString,
+    ascii::{NonEmptyAsciiStr, NonEmptyAsciiString},
+    blank::{NonBlankStr, NonBlankString},
+    bounded::{BoundedStr, BoundedString, Measure, Unit},
+    small_string::NonEmptySmallString,
+    str::NonEmptyStr,
+    string::NonEmptyString,
+    validated::{ValidatedStr, ValidatedString},
+};
+
+// NOTE: `schemars` forwards implementations for `Box<T>`, `Cow<'_, T>`, `Rc<T>` and `Arc<T>`,
+// therefore boxed, cow, rc and arc non-empty strings are covered by `NonEmptyStr` alone
+
+// NOTE: `\S` is not used, since whitespace in ECMA-262 differs from `char::is_whitespace`
+// (for instance, U+0085 is whitespace only in the latter, and U+FEFF only in the former)
+const NON_BLANK_PATTERN: &str =
+    r"[^\t-\r \u0085\u00A0\u1680\u2000-\u200A\u2028\u2029\u202F\u205F\u3000]";
+
+const ASCII_PATTERN: &str = r"^[\x00-\x7F]+$";
+
+fn non_empty_schema() -> Schema {
+    json_schema!({
+        "type": "string",
+        "minLength": 1
+    })
+}
+
+macro_rules! impl_non_empty_schema {
+    ($($type: ty),+ $(,)?) => {
+        $(
+            impl JsonSchema for $type {
+                fn inline_schema() -> bool {
+                    true
+                }
+
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Borrowed("NonEmptyString")
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    non_empty_schema()
+                }
+            }
+        )+
+    };
+}
+
+impl_non_empty_schema!(NonEmptyStr, NonEmptyString, NonEmptySmallString);
+
+impl<V> JsonSchema for ValidatedStr<V> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonEmptyString")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        non_empty_schema()
+    }
+}
+
+impl<V> JsonSchema for ValidatedString<V> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonEmptyString")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        non_empty_schema()
+    }
+}
+
+macro_rules! impl_pattern_schema {
+    ($name: literal, $pattern: expr => $($type: ty),+ $(,)?) => {
+        $(
+            impl JsonSchema for $type {
+                fn inline_schema() -> bool {
+                    true
+                }
+
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Borrowed($name)
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "minLength": 1,
+                        "pattern": $pattern
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_pattern_schema!("NonBlankString", NON_BLANK_PATTERN => NonBlankStr, NonBlankString);
+
+impl_pattern_schema!("NonEmptyAsciiString", ASCII_PATTERN => NonEmptyAsciiStr, NonEmptyAsciiString);
+
+// NOTE: JSON Schema measures length in characters, and since every character takes
+// from one to four bytes in UTF-8, byte bounds can only be approximated
+
+const MAX_UTF8_LEN: usize = 4;
+
+fn bounded_schema(min: usize, max: usize) -> Schema {
+    json_schema!({
+        "type": "string",
+        "minLength": min,
+        "maxLength": max
+    })
+}
+
+impl<const N: usize> JsonSchema for NonEmptyArrayString<N> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("NonEmptyArrayString_{N}"))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        bounded_schema(1, N)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M: Measure> JsonSchema for BoundedStr<MIN, MAX, M> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("BoundedString_{MIN}_{MAX}_{}", M::UNIT))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let min = match M::UNIT {
+            Unit::Bytes => MIN.div_ceil(MAX_UTF8_LEN),
+            Unit::Chars => MIN,
+        };
+
+        bounded_schema(min, MAX)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M: Measure> JsonSchema for BoundedString<MIN, MAX, M> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        BoundedStr::<MIN, MAX, M>::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        BoundedStr::<MIN, MAX, M>::json_schema(generator)
+    }
+}
@@ -0,0 +1,103 @@
+//! Unicode segmentation of non-empty strings.
+
+#[cfg(not(feature = "unicode-segmentation"))]
+compile_error!("expected `unicode-segmentation` to be enabled");
+
+use non_empty_iter::NonEmptyIterator;
+use non_zero_size::Size;
+
+use crate::{
+    iter::{
+        GraphemeIndices, Graphemes, SplitSentenceBounds, SplitWordBounds, UnicodeSentences,
+        UnicodeWords,
+    },
+    str::NonEmptyStr,
+};
+
+impl NonEmptyStr {
+    /// Returns non-empty iterators over the grapheme clusters of this string.
+    ///
+    /// If `is_extended` is [`true`], extended grapheme clusters are used,
+    /// otherwise legacy grapheme clusters are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_iter::NonEmptyIterator;
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let name = non_empty_str!("e\u{301}lan");
+    ///
+    /// let (first, _) = name.graphemes(true).consume();
+    ///
+    /// assert_eq!(first, non_empty_str!("e\u{301}"));
+    /// ```
+    #[must_use]
+    pub const fn graphemes(&self, is_extended: bool) -> Graphemes<'_> {
+        Graphemes::new(self, is_extended)
+    }
+
+    /// Returns non-empty iterators over the grapheme clusters of this string and their positions.
+    ///
+    /// See [`graphemes`] for more information.
+    ///
+    /// [`graphemes`]: Self::graphemes
+    #[must_use]
+    pub const fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_> {
+        GraphemeIndices::new(self, is_extended)
+    }
+
+    /// Returns iterators over the words of this string, according to the Unicode word boundaries.
+    ///
+    /// Only substrings containing alphanumeric characters are considered words.
+    #[must_use]
+    pub const fn unicode_words(&self) -> UnicodeWords<'_> {
+        UnicodeWords::new(self)
+    }
+
+    /// Returns non-empty iterators over the substrings of this string,
+    /// separated by the Unicode word boundaries.
+    #[must_use]
+    pub const fn split_word_bounds(&self) -> SplitWordBounds<'_> {
+        SplitWordBounds::new(self)
+    }
+
+    /// Returns iterators over the sentences of this string,
+    /// according to the Unicode sentence boundaries.
+    ///
+    /// Only substrings containing alphanumeric characters are considered sentences.
+    #[must_use]
+    pub const fn unicode_sentences(&self) -> UnicodeSentences<'_> {
+        UnicodeSentences::new(self)
+    }
+
+    /// Returns non-empty iterators over the substrings of this string,
+    /// separated by the Unicode sentence boundaries.
+    #[must_use]
+    pub const fn split_sentence_bounds(&self) -> SplitSentenceBounds<'_> {
+        SplitSentenceBounds::new(self)
+    }
+
+    /// Returns the number of extended grapheme clusters in this string as [`Size`].
+    #[must_use]
+    pub fn grapheme_count(&self) -> Size {
+        self.graphemes(true).count()
+    }
+
+    /// Returns the first extended grapheme cluster of this string.
+    #[must_use]
+    pub fn first_grapheme(&self) -> &Self {
+        let (first, _) = self.graphemes(true).consume();
+
+        first
+    }
+
+    /// Returns the last extended grapheme cluster of this string.
+    #[must_use]
+    pub fn last_grapheme(&self) -> &Self {
+        self.graphemes(true)
+            .into_iter()
+            .next_back()
+            .expect("expected non-empty string")
+    }
+}
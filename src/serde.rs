@@ -1,15 +1,39 @@
 #[cfg(not(feature = "serde"))]
 compile_error!("expected `serde` to be enabled");
 
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::string::String;
 
+use core::fmt;
+
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
 
-use crate::str::NonEmptyStr;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use serde::de::Visitor;
+
+use crate::{
+    ascii::NonEmptyAsciiStr,
+    blank::NonBlankStr,
+    bounded::{BoundedStr, Measure},
+    str::NonEmptyStr,
+    validated::{MaybeEmptyValidationError, ValidatedStr, Validator},
+};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-use crate::{boxed::NonEmptyBoxedStr, string::NonEmptyString};
+use crate::{
+    ascii::NonEmptyAsciiString, blank::NonBlankString, bounded::BoundedString,
+    boxed::NonEmptyBoxedStr, small_string::NonEmptySmallString, string::NonEmptyString,
+    validated::ValidatedString,
+};
+
+#[cfg(feature = "std")]
+use crate::path::{NonEmptyPath, NonEmptyPathBuf};
+
+#[cfg(feature = "unicode-normalization")]
+use crate::normalization::NormalizedNonEmptyString;
 
 impl Serialize for NonEmptyStr {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -54,3 +78,225 @@ impl<'de> Deserialize<'de> for NonEmptyBoxedStr {
         Ok(non_empty_string.into_non_empty_boxed_str())
     }
 }
+
+impl Serialize for NonBlankStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de: 's, 's> Deserialize<'de> for &'s NonBlankStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = <&str>::deserialize(deserializer)?;
+
+        let non_blank = string.try_into().map_err(D::Error::custom)?;
+
+        Ok(non_blank)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Serialize for NonBlankString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_string().serialize(serializer)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de> Deserialize<'de> for NonBlankString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        Self::new(string).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for NonEmptyAsciiStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de: 's, 's> Deserialize<'de> for &'s NonEmptyAsciiStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = <&str>::deserialize(deserializer)?;
+
+        let ascii = string.try_into().map_err(D::Error::custom)?;
+
+        Ok(ascii)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Serialize for NonEmptyAsciiString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_string().serialize(serializer)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de> Deserialize<'de> for NonEmptyAsciiString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        Self::new(string).map_err(D::Error::custom)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize, M> Serialize for BoundedStr<MIN, MAX, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de: 's, 's, const MIN: usize, const MAX: usize, M: Measure> Deserialize<'de>
+    for &'s BoundedStr<MIN, MAX, M>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = <&str>::deserialize(deserializer)?;
+
+        let bounded = string.try_into().map_err(D::Error::custom)?;
+
+        Ok(bounded)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<const MIN: usize, const MAX: usize, M> Serialize for BoundedString<MIN, MAX, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_string().serialize(serializer)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de, const MIN: usize, const MAX: usize, M: Measure> Deserialize<'de>
+    for BoundedString<MIN, MAX, M>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        Self::new(string).map_err(D::Error::custom)
+    }
+}
+
+impl<V> Serialize for ValidatedStr<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de: 's, 's, V: Validator<Error: fmt::Display>> Deserialize<'de> for &'s ValidatedStr<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = <&str>::deserialize(deserializer)?;
+
+        let validated = ValidatedStr::try_from_str(string).map_err(|error| match error {
+            MaybeEmptyValidationError::Empty(error) => D::Error::custom(error),
+            MaybeEmptyValidationError::Invalid(error) => D::Error::custom(error),
+        })?;
+
+        Ok(validated)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Serialize for ValidatedString<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_string().serialize(serializer)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de, V: Validator<Error: fmt::Display>> Deserialize<'de> for ValidatedString<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let non_empty = NonEmptyString::deserialize(deserializer)?;
+
+        Self::from_non_empty_string(non_empty).map_err(|error| D::Error::custom(error.error()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Serialize for NonEmptySmallString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+// NOTE: visiting borrowed strings directly allows short strings to be stored inline
+// without going through the intermediate `String` allocation
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+struct NonEmptySmallStringVisitor;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Visitor<'_> for NonEmptySmallStringVisitor {
+    type Value = NonEmptySmallString;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("non-empty string")
+    }
+
+    fn visit_str<E: Error>(self, string: &str) -> Result<Self::Value, E> {
+        string.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de> Deserialize<'de> for NonEmptySmallString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(NonEmptySmallStringVisitor)
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl Serialize for NormalizedNonEmptyString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<'de> Deserialize<'de> for NormalizedNonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let non_empty = NonEmptyString::deserialize(deserializer)?;
+
+        Ok(Self::new(non_empty))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for NonEmptyPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_path().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de: 'p, 'p> Deserialize<'de> for &'p NonEmptyPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = <&Path>::deserialize(deserializer)?;
+
+        let non_empty = path.try_into().map_err(D::Error::custom)?;
+
+        Ok(non_empty)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for NonEmptyPathBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_path_buf().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for NonEmptyPathBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path_buf = PathBuf::deserialize(deserializer)?;
+
+        Self::new(path_buf).map_err(D::Error::custom)
+    }
+}
+
+// NOTE: `Serialize` and `Deserialize` are implemented for `Rc<T>` and `Arc<T>` via the `rc` feature
+// of `serde`, provided `T: Serialize` and `Box<T>: Deserialize` respectively, therefore
+// `NonEmptyRcStr` and `NonEmptyArcStr` are both `Serialize` and `Deserialize` with `serde-rc`
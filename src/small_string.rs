@@ -0,0 +1,893 @@
+//! Non-empty small-string-optimized strings.
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::String;
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    num::NonZeroU8,
+    ops::{Deref, DerefMut},
+    ptr, slice,
+    str::FromStr,
+};
+
+use non_empty_iter::{FromNonEmptyIterator, IntoNonEmptyIterator, NonEmptyIterator};
+use non_zero_size::Size;
+
+use crate::{
+    array_string::NonEmptyArrayString,
+    internal::Bytes,
+    str::{EmptyStr, NonEmptyStr},
+    string::{EmptyString, NonEmptyString},
+};
+
+/// The amount of bytes [`NonEmptySmallString`] can store inline, without allocating.
+///
+/// This is one byte less than the size of [`NonEmptyString`], which is 23 bytes on 64-bit targets.
+pub const INLINE_CAPACITY: usize = size_of::<Heap>() - 1;
+
+/// Represents the inline strings used by [`NonEmptySmallString`].
+pub type NonEmptyInlineString = NonEmptyArrayString<INLINE_CAPACITY>;
+
+const HEAP_TAG: u8 = 0x80;
+
+const _: () = assert!(INLINE_CAPACITY < HEAP_TAG as usize);
+
+// NOTE: heap strings are stored as raw parts, with the capacity inverted and in little-endian,
+// so that the last byte always has `HEAP_TAG` set, since capacities never exceed `isize::MAX`
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Heap {
+    ptr: *mut u8,
+    len: usize,
+    cap: usize,
+}
+
+impl Heap {
+    const fn capacity(self) -> usize {
+        !usize::from_le(self.cap)
+    }
+}
+
+// NOTE: the last byte is either the length of the inline string or has `HEAP_TAG` set;
+// it is never zero, which leaves the niche for `Option<NonEmptySmallString>`
+#[repr(C)]
+struct Repr {
+    align: [Heap; 0],
+    bytes: [MaybeUninit<u8>; INLINE_CAPACITY],
+    tag: NonZeroU8,
+}
+
+// SAFETY: `Repr` uniquely owns its heap allocation, just like `String` does
+unsafe impl Send for Repr {}
+
+// SAFETY: `Repr` only allows mutating its contents through mutable references
+unsafe impl Sync for Repr {}
+
+impl Repr {
+    const fn from_inline(inline: &NonEmptyInlineString) -> Self {
+        let len = inline.len().get();
+
+        let mut bytes = [MaybeUninit::uninit(); INLINE_CAPACITY];
+
+        // SAFETY: the length of inline strings does not exceed `INLINE_CAPACITY`
+        unsafe {
+            ptr::copy_nonoverlapping(inline.as_bytes().as_ptr(), bytes.as_mut_ptr().cast(), len);
+        }
+
+        // SAFETY: the length of inline strings is non-zero
+        let tag = unsafe { NonZeroU8::new_unchecked(len as u8) };
+
+        Self {
+            align: [],
+            bytes,
+            tag,
+        }
+    }
+
+    const fn from_heap(mut non_empty: NonEmptyString) -> Self {
+        // SAFETY: the string is only used to take its raw parts
+        let bytes = unsafe { non_empty.as_mut_string().as_mut_vec() };
+
+        let heap = Heap {
+            ptr: bytes.as_mut_ptr(),
+            len: bytes.len(),
+            cap: (!bytes.capacity()).to_le(),
+        };
+
+        // NOTE: the ownership of the allocation is transferred to the representation
+        mem::forget(non_empty);
+
+        let mut repr = MaybeUninit::<Self>::uninit();
+
+        // SAFETY: `Heap` initializes every byte of `Self`, and the last one has `HEAP_TAG` set
+        unsafe {
+            repr.as_mut_ptr().cast::<Heap>().write(heap);
+
+            repr.assume_init()
+        }
+    }
+
+    const fn is_inline(&self) -> bool {
+        self.tag.get() & HEAP_TAG == 0
+    }
+
+    // NOTE: this must only be called for strings stored on the heap
+    const fn heap(&self) -> Heap {
+        // SAFETY: heap strings initialize every byte of `self`
+        unsafe { ptr::from_ref(self).cast::<Heap>().read() }
+    }
+
+    const fn len(&self) -> usize {
+        if self.is_inline() {
+            self.tag.get() as usize
+        } else {
+            self.heap().len
+        }
+    }
+
+    const fn capacity(&self) -> usize {
+        if self.is_inline() {
+            INLINE_CAPACITY
+        } else {
+            self.heap().capacity()
+        }
+    }
+
+    const fn as_str(&self) -> &str {
+        let ptr = if self.is_inline() {
+            self.bytes.as_ptr().cast()
+        } else {
+            self.heap().ptr.cast_const()
+        };
+
+        // SAFETY: the first `len` bytes are initialized and valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(slice::from_raw_parts(ptr, self.len())) }
+    }
+
+    const fn as_mut_str(&mut self) -> &mut str {
+        let len = self.len();
+
+        let ptr = if self.is_inline() {
+            self.bytes.as_mut_ptr().cast()
+        } else {
+            self.heap().ptr
+        };
+
+        // SAFETY: the first `len` bytes are initialized and valid UTF-8
+        unsafe { core::str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr, len)) }
+    }
+
+    const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_str_unchecked(self.as_str()) }
+    }
+
+    fn inline(&self) -> Option<NonEmptyInlineString> {
+        if !self.is_inline() {
+            return None;
+        }
+
+        NonEmptyInlineString::from_non_empty_str(self.as_non_empty_str()).ok()
+    }
+
+    // NOTE: the returned string shares the allocation with `self`, so the caller
+    // must ensure that only one of them is dropped
+    unsafe fn take_heap(&self) -> NonEmptyString {
+        let heap = self.heap();
+
+        // SAFETY: the raw parts come from the non-empty string owned by `self`
+        unsafe {
+            NonEmptyString::new_unchecked(String::from_raw_parts(
+                heap.ptr,
+                heap.len,
+                heap.capacity(),
+            ))
+        }
+    }
+
+    fn heap_mut(&mut self) -> Option<HeapGuard<'_>> {
+        if self.is_inline() {
+            return None;
+        }
+
+        // SAFETY: the guard writes the string back into `self` without dropping either
+        let heap = ManuallyDrop::new(unsafe { self.take_heap() });
+
+        Some(HeapGuard { repr: self, heap })
+    }
+
+    fn update<T, I, H>(&mut self, inline: I, heap: H) -> T
+    where
+        I: FnOnce(&mut NonEmptyInlineString) -> T,
+        H: FnOnce(&mut NonEmptyString) -> T,
+    {
+        if let Some(mut guard) = self.heap_mut() {
+            return heap(&mut guard);
+        }
+
+        let Some(mut string) = self.inline() else {
+            unreachable!()
+        };
+
+        // NOTE: inline strings are copied out and back in, which is cheap given their capacity
+        let output = inline(&mut string);
+
+        *self = Self::from_inline(&string);
+
+        output
+    }
+
+    fn into_non_empty_string(self) -> NonEmptyString {
+        let this = ManuallyDrop::new(self);
+
+        if this.is_inline() {
+            this.as_non_empty_str().to_non_empty_string()
+        } else {
+            // SAFETY: `this` is never dropped, so the string is the only owner
+            unsafe { this.take_heap() }
+        }
+    }
+}
+
+impl Clone for Repr {
+    fn clone(&self) -> Self {
+        if self.is_inline() {
+            // SAFETY: inline strings do not own any resources, so they can be copied
+            unsafe { ptr::read(self) }
+        } else {
+            Self::from_heap(self.as_non_empty_str().to_non_empty_string())
+        }
+    }
+}
+
+impl Drop for Repr {
+    fn drop(&mut self) {
+        if !self.is_inline() {
+            // SAFETY: `self` is being dropped, so the string is the only owner
+            drop(unsafe { self.take_heap() });
+        }
+    }
+}
+
+// NOTE: writes the (possibly reallocated) heap string back when dropped, even on panics
+struct HeapGuard<'r> {
+    repr: &'r mut Repr,
+    heap: ManuallyDrop<NonEmptyString>,
+}
+
+impl Deref for HeapGuard<'_> {
+    type Target = NonEmptyString;
+
+    fn deref(&self) -> &Self::Target {
+        &self.heap
+    }
+}
+
+impl DerefMut for HeapGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.heap
+    }
+}
+
+impl Drop for HeapGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: the string is not used after being taken
+        let heap = unsafe { ManuallyDrop::take(&mut self.heap) };
+
+        // NOTE: the previous representation shares the allocation, so it must not be dropped
+        mem::forget(mem::replace(self.repr, Repr::from_heap(heap)));
+    }
+}
+
+/// Represents non-empty strings that store short contents inline,
+/// only allocating on the heap when the contents do not fit.
+///
+/// Strings of up to [`INLINE_CAPACITY`] bytes are stored inline.
+///
+/// This type takes as much space as [`NonEmptyString`], and so does
+/// [`Option<NonEmptySmallString>`], since [`None`] is encoded in the niche.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::{NonEmptySmallString, non_empty_str};
+///
+/// let mut string = NonEmptySmallString::from_non_empty_str(non_empty_str!("nekit"));
+///
+/// assert!(string.is_inline());
+///
+/// string.push_str(" is writing a rather long string");
+///
+/// assert!(!string.is_inline());
+/// ```
+#[derive(Clone)]
+pub struct NonEmptySmallString {
+    repr: Repr,
+}
+
+const _: () = assert!(size_of::<NonEmptySmallString>() == size_of::<NonEmptyString>());
+
+const _: () = assert!(size_of::<Option<NonEmptySmallString>>() == size_of::<NonEmptyString>());
+
+impl fmt::Debug for NonEmptySmallString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl fmt::Display for NonEmptySmallString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl fmt::Write for NonEmptySmallString {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string);
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, character: char) -> fmt::Result {
+        self.push(character);
+
+        Ok(())
+    }
+}
+
+impl PartialEq for NonEmptySmallString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for NonEmptySmallString {}
+
+impl PartialOrd for NonEmptySmallString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonEmptySmallString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for NonEmptySmallString {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_non_empty_str().hash(hasher);
+    }
+}
+
+impl FromStr for NonEmptySmallString {
+    type Err = EmptyStr;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        NonEmptyStr::try_from_str(string).map(Self::from_non_empty_str)
+    }
+}
+
+impl TryFrom<&str> for NonEmptySmallString {
+    type Error = EmptyStr;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl TryFrom<String> for NonEmptySmallString {
+    type Error = EmptyString;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        NonEmptyString::new(string).map(Self::from_non_empty_string)
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptySmallString {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        Self::from_non_empty_str(non_empty)
+    }
+}
+
+impl From<NonEmptyString> for NonEmptySmallString {
+    fn from(non_empty: NonEmptyString) -> Self {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+impl From<NonEmptyInlineString> for NonEmptySmallString {
+    fn from(inline: NonEmptyInlineString) -> Self {
+        Self::from_inline(inline)
+    }
+}
+
+impl From<char> for NonEmptySmallString {
+    fn from(character: char) -> Self {
+        Self::single(character)
+    }
+}
+
+impl From<NonEmptySmallString> for NonEmptyString {
+    fn from(small: NonEmptySmallString) -> Self {
+        small.into_non_empty_string()
+    }
+}
+
+impl From<NonEmptySmallString> for String {
+    fn from(small: NonEmptySmallString) -> Self {
+        small.into_string()
+    }
+}
+
+impl Borrow<NonEmptyStr> for NonEmptySmallString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl BorrowMut<NonEmptyStr> for NonEmptySmallString {
+    fn borrow_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl Borrow<str> for NonEmptySmallString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl BorrowMut<str> for NonEmptySmallString {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<Self> for NonEmptySmallString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsMut<Self> for NonEmptySmallString {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonEmptySmallString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl AsMut<NonEmptyStr> for NonEmptySmallString {
+    fn as_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl AsRef<str> for NonEmptySmallString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsMut<str> for NonEmptySmallString {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<Bytes> for NonEmptySmallString {
+    fn as_ref(&self) -> &Bytes {
+        self.as_bytes()
+    }
+}
+
+impl Deref for NonEmptySmallString {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl DerefMut for NonEmptySmallString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl<'s> Extend<&'s str> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = &'s str>>(&mut self, iterable: I) {
+        iterable
+            .into_iter()
+            .for_each(|string| self.push_str(string));
+    }
+}
+
+impl<'s> Extend<&'s NonEmptyStr> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = &'s NonEmptyStr>>(&mut self, iterable: I) {
+        self.extend(iterable.into_iter().map(NonEmptyStr::as_str));
+    }
+}
+
+impl Extend<char> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iterable: I) {
+        iterable
+            .into_iter()
+            .for_each(|character| self.push(character));
+    }
+}
+
+impl<'c> Extend<&'c char> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = &'c char>>(&mut self, iterable: I) {
+        self.extend(iterable.into_iter().copied());
+    }
+}
+
+impl Extend<String> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, iterable: I) {
+        iterable
+            .into_iter()
+            .for_each(|string| self.push_str(&string));
+    }
+}
+
+impl Extend<NonEmptyString> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = NonEmptyString>>(&mut self, iterable: I) {
+        iterable
+            .into_iter()
+            .for_each(|non_empty| self.push_str(&non_empty));
+    }
+}
+
+impl Extend<Self> for NonEmptySmallString {
+    fn extend<I: IntoIterator<Item = Self>>(&mut self, iterable: I) {
+        iterable.into_iter().for_each(|small| self.push_str(&small));
+    }
+}
+
+impl NonEmptySmallString {
+    /// Constructs [`Self`] from [`NonEmptyStr`], storing it inline if it fits.
+    #[must_use]
+    pub fn from_non_empty_str(non_empty: &NonEmptyStr) -> Self {
+        let repr = NonEmptyInlineString::from_non_empty_str(non_empty).map_or_else(
+            |_| Repr::from_heap(non_empty.to_non_empty_string()),
+            |inline| Repr::from_inline(&inline),
+        );
+
+        Self { repr }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyString`], reusing its allocation.
+    ///
+    /// Use [`shrink_to_fit`] in order to move the contents inline, provided they fit.
+    ///
+    /// [`shrink_to_fit`]: Self::shrink_to_fit
+    #[must_use]
+    pub const fn from_non_empty_string(non_empty: NonEmptyString) -> Self {
+        Self {
+            repr: Repr::from_heap(non_empty),
+        }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyInlineString`].
+    #[must_use]
+    pub const fn from_inline(inline: NonEmptyInlineString) -> Self {
+        Self {
+            repr: Repr::from_inline(&inline),
+        }
+    }
+
+    /// Constructs [`Self`] containing single provided character.
+    #[must_use]
+    pub fn single(character: char) -> Self {
+        let mut buffer = [0; 4];
+
+        let string = character.encode_utf8(&mut buffer);
+
+        // SAFETY: encoding any character results in non-empty string
+        Self::from_non_empty_str(unsafe { NonEmptyStr::from_str_unchecked(string) })
+    }
+
+    /// Checks if the string is stored inline.
+    #[must_use]
+    pub const fn is_inline(&self) -> bool {
+        self.repr.is_inline()
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        // SAFETY: the string is non-empty by construction
+        unsafe { Size::new_unchecked(self.repr.len()) }
+    }
+
+    /// Returns the capacity of the string in bytes as [`Size`].
+    ///
+    /// This is [`INLINE_CAPACITY`] for strings stored inline.
+    #[must_use]
+    pub const fn capacity(&self) -> Size {
+        // SAFETY: capacity is always non-zero for non-empty strings
+        unsafe { Size::new_unchecked(self.repr.capacity()) }
+    }
+
+    /// Extracts the string slice containing the entire string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.repr.as_str()
+    }
+
+    /// Returns the mutable string slice containing the entire string.
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        self.repr.as_mut_str()
+    }
+
+    /// Returns contained string reference as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.repr.as_non_empty_str()
+    }
+
+    /// Returns contained mutable string reference as [`NonEmptyStr`].
+    pub const fn as_non_empty_mut_str(&mut self) -> &mut NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_mut_str_unchecked(self.as_mut_str()) }
+    }
+
+    /// Returns the underlying bytes of the string.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        self.as_str().as_bytes()
+    }
+
+    /// Converts [`Self`] into [`NonEmptyString`], allocating if the string is stored inline.
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.repr.into_non_empty_string()
+    }
+
+    /// Converts [`Self`] into [`String`], allocating if the string is stored inline.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.into_non_empty_string().into_string()
+    }
+
+    // NOTE: moves the contents to the heap, reserving space for `additional` more bytes
+    fn spill(&mut self, additional: usize) -> HeapGuard<'_> {
+        if self.is_inline() {
+            let mut string = String::with_capacity(self.len().get() + additional);
+
+            string.push_str(self.as_str());
+
+            // SAFETY: the inline string is non-empty by construction
+            self.repr = Repr::from_heap(unsafe { NonEmptyString::new_unchecked(string) });
+        }
+
+        match self.repr.heap_mut() {
+            Some(heap) => heap,
+            None => unreachable!(),
+        }
+    }
+
+    /// Appends the given [`char`] to the end of this string.
+    pub fn push(&mut self, character: char) {
+        self.push_str(character.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends the given [`str`] onto the end of this string.
+    ///
+    /// Moves the string to the heap if the result does not fit inline.
+    pub fn push_str(&mut self, string: &str) {
+        if let Some(mut inline) = self.repr.inline()
+            && inline.push_str(string).is_ok()
+        {
+            self.repr = Repr::from_inline(&inline);
+
+            return;
+        }
+
+        self.spill(string.len()).push_str(string);
+    }
+
+    /// Appends anything that can be converted to string onto the end of this string.
+    pub fn extend_from<S: AsRef<str>>(&mut self, string: S) {
+        self.push_str(string.as_ref());
+    }
+
+    /// Inserts the given character at the specified index,
+    /// shifting all bytes after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn insert(&mut self, index: usize, character: char) {
+        self.insert_str(index, character.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts the given string at the specified index, shifting all bytes after it to the right.
+    ///
+    /// Moves the string to the heap if the result does not fit inline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn insert_str(&mut self, index: usize, string: &str) {
+        if let Some(mut inline) = self.repr.inline()
+            && inline.insert_str(index, string).is_ok()
+        {
+            self.repr = Repr::from_inline(&inline);
+
+            return;
+        }
+
+        self.spill(string.len()).insert_str(index, string);
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be added.
+    ///
+    /// Moves the string to the heap if the reserved capacity does not fit inline.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    pub fn reserve(&mut self, additional: Size) {
+        if let Some(mut heap) = self.repr.heap_mut() {
+            heap.reserve(additional);
+        } else if INLINE_CAPACITY - self.len().get() < additional.get() {
+            self.spill(additional.get());
+        }
+    }
+
+    /// Shrinks the capacity of the string as much as possible,
+    /// moving the contents inline if they fit.
+    pub fn shrink_to_fit(&mut self) {
+        if self.is_inline() {
+            return;
+        }
+
+        match NonEmptyInlineString::from_non_empty_str(self.as_non_empty_str()) {
+            Ok(inline) => self.repr = Repr::from_inline(&inline),
+            Err(_) => {
+                if let Some(mut heap) = self.repr.heap_mut() {
+                    heap.shrink_to_fit();
+                }
+            }
+        }
+    }
+
+    /// Shortens this string to the specified non-zero length.
+    ///
+    /// Does nothing if `new` is greater than or equal to the string's [`len`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new` does not lie on character boundary.
+    ///
+    /// [`len`]: Self::len
+    pub fn truncate(&mut self, new: Size) {
+        self.repr
+            .update(|inline| inline.truncate(new), |heap| heap.truncate(new));
+    }
+
+    /// Checks whether the string is almost empty, meaning it only contains one character.
+    #[must_use]
+    pub fn next_empty(&self) -> bool {
+        self.len().get() - self.first_char().len_utf8() == 0
+    }
+
+    /// The negated version of [`next_empty`].
+    ///
+    /// [`next_empty`]: Self::next_empty
+    #[must_use]
+    pub fn next_non_empty(&self) -> bool {
+        !self.next_empty()
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or [`None`] if the string would become empty.
+    pub fn pop(&mut self) -> Option<char> {
+        self.repr
+            .update(NonEmptyInlineString::pop, NonEmptyString::pop)
+    }
+
+    /// Removes and returns the character at the given index within the string,
+    /// shifting all bytes after it to the left.
+    ///
+    /// Returns [`None`] if the string would become empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or not on character boundary.
+    pub fn remove(&mut self, index: usize) -> Option<char> {
+        self.repr
+            .update(|inline| inline.remove(index), |heap| heap.remove(index))
+    }
+}
+
+impl NonEmptyStr {
+    /// Converts [`Self`] to [`NonEmptySmallString`], storing it inline if it fits.
+    #[must_use]
+    pub fn to_non_empty_small_string(&self) -> NonEmptySmallString {
+        NonEmptySmallString::from_non_empty_str(self)
+    }
+}
+
+impl FromNonEmptyIterator<char> for NonEmptySmallString {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = char>>(iterable: I) -> Self {
+        let (character, iterator) = iterable.into_non_empty_iter().consume();
+
+        let mut output = Self::single(character);
+
+        output.extend(iterator);
+
+        output
+    }
+}
+
+impl<'c> FromNonEmptyIterator<&'c char> for NonEmptySmallString {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = &'c char>>(iterable: I) -> Self {
+        let (&character, iterator) = iterable.into_non_empty_iter().consume();
+
+        let mut output = Self::single(character);
+
+        output.extend(iterator);
+
+        output
+    }
+}
+
+impl<'s> FromNonEmptyIterator<&'s NonEmptyStr> for NonEmptySmallString {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = &'s NonEmptyStr>>(iterable: I) -> Self {
+        let (non_empty, iterator) = iterable.into_non_empty_iter().consume();
+
+        let mut output = Self::from_non_empty_str(non_empty);
+
+        output.extend(iterator);
+
+        output
+    }
+}
+
+impl FromNonEmptyIterator<NonEmptyString> for NonEmptySmallString {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = NonEmptyString>>(iterable: I) -> Self {
+        let (non_empty, iterator) = iterable.into_non_empty_iter().consume();
+
+        let mut output = Self::from_non_empty_string(non_empty);
+
+        output.extend(iterator);
+
+        output
+    }
+}
+
+impl FromNonEmptyIterator<Self> for NonEmptySmallString {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = Self>>(iterable: I) -> Self {
+        let (mut output, iterator) = iterable.into_non_empty_iter().consume();
+
+        output.extend(iterator);
+
+        output
+    }
+}
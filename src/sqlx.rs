@@ -0,0 +1,173 @@
+#[cfg(not(feature = "sqlx"))]
+compile_error!("expected `sqlx` to be enabled");
+
+use std::string::String;
+
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+use crate::{boxed::NonEmptyBoxedStr, str::NonEmptyStr, string::NonEmptyString};
+
+// NOTE: implementations are generic over databases and delegate to the ones for standard strings,
+// which means that every backend enabled in `sqlx` is supported without extra features
+
+impl<D: Database> Type<D> for NonEmptyStr
+where
+    str: Type<D>,
+{
+    fn type_info() -> D::TypeInfo {
+        <str as Type<D>>::type_info()
+    }
+
+    fn compatible(type_info: &D::TypeInfo) -> bool {
+        <str as Type<D>>::compatible(type_info)
+    }
+}
+
+impl<'q, D: Database> Encode<'q, D> for &'q NonEmptyStr
+where
+    &'q str: Encode<'q, D>,
+{
+    fn encode_by_ref(
+        &self,
+        buffer: &mut <D as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let string: &'q str = (*self).as_str();
+
+        string.encode(buffer)
+    }
+
+    fn produces(&self) -> Option<D::TypeInfo> {
+        let string: &'q str = (*self).as_str();
+
+        string.produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        let string: &'q str = (*self).as_str();
+
+        string.size_hint()
+    }
+}
+
+impl<'r, D: Database> Decode<'r, D> for &'r NonEmptyStr
+where
+    &'r str: Decode<'r, D>,
+{
+    /// Decodes the borrowed string, failing with [`EmptyStr`] if it is empty.
+    ///
+    /// Unlike owned decoding, which fails with [`EmptyString`], this does not allocate.
+    ///
+    /// [`EmptyStr`]: crate::str::EmptyStr
+    /// [`EmptyString`]: crate::string::EmptyString
+    fn decode(value: <D as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let string = <&str>::decode(value)?;
+
+        let non_empty = NonEmptyStr::try_from_str(string)?;
+
+        Ok(non_empty)
+    }
+}
+
+impl<D: Database> Type<D> for NonEmptyString
+where
+    str: Type<D>,
+{
+    fn type_info() -> D::TypeInfo {
+        <str as Type<D>>::type_info()
+    }
+
+    fn compatible(type_info: &D::TypeInfo) -> bool {
+        <str as Type<D>>::compatible(type_info)
+    }
+}
+
+impl<'q, D: Database> Encode<'q, D> for NonEmptyString
+where
+    String: Encode<'q, D>,
+{
+    fn encode(
+        self,
+        buffer: &mut <D as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.into_string().encode(buffer)
+    }
+
+    fn encode_by_ref(
+        &self,
+        buffer: &mut <D as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.as_string().encode_by_ref(buffer)
+    }
+
+    fn produces(&self) -> Option<D::TypeInfo> {
+        self.as_string().produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        self.as_string().size_hint()
+    }
+}
+
+impl<'r, D: Database> Decode<'r, D> for NonEmptyString
+where
+    String: Decode<'r, D>,
+{
+    /// Decodes the owned string, failing with [`EmptyString`] if it is empty.
+    ///
+    /// [`EmptyString`]: crate::string::EmptyString
+    fn decode(value: <D as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let string = String::decode(value)?;
+
+        let non_empty = Self::new(string)?;
+
+        Ok(non_empty)
+    }
+}
+
+impl<D: Database> Type<D> for NonEmptyBoxedStr
+where
+    str: Type<D>,
+{
+    fn type_info() -> D::TypeInfo {
+        <str as Type<D>>::type_info()
+    }
+
+    fn compatible(type_info: &D::TypeInfo) -> bool {
+        <str as Type<D>>::compatible(type_info)
+    }
+}
+
+impl<'q, D: Database> Encode<'q, D> for NonEmptyBoxedStr
+where
+    String: Encode<'q, D>,
+{
+    fn encode(
+        self,
+        buffer: &mut <D as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.into_non_empty_string().into_string().encode(buffer)
+    }
+
+    /// Encodes the boxed string by reference.
+    ///
+    /// This allocates a copy of the string, since the encoded value can not borrow from `self`.
+    /// Binding boxed strings by value avoids the allocation.
+    fn encode_by_ref(
+        &self,
+        buffer: &mut <D as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.as_str().to_owned().encode(buffer)
+    }
+}
+
+impl<'r, D: Database> Decode<'r, D> for NonEmptyBoxedStr
+where
+    String: Decode<'r, D>,
+{
+    /// Decodes the boxed string, failing with [`EmptyString`] if it is empty.
+    ///
+    /// [`EmptyString`]: crate::string::EmptyString
+    fn decode(value: <D as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        NonEmptyString::decode(value).map(NonEmptyString::into_non_empty_boxed_str)
+    }
+}
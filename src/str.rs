@@ -1,25 +1,35 @@
 //! Non-empty [`str`].
+//!
+//! Since the [`Pattern`] trait is unstable, splitting methods accept [`Separator`] values instead,
+//! which are implemented for characters, string slices and character predicates, while trimming
+//! methods accept character predicates only.
+//!
+//! [`Pattern`]: core::str::pattern::Pattern
 
 #[cfg(feature = "std")]
 use std::{ffi::OsStr, path::Path};
 
 use core::{
     fmt,
+    iter::FusedIterator,
     ops::{Deref, DerefMut, Index, IndexMut},
     ptr,
     slice::SliceIndex,
     str::Utf8Error,
 };
 
+use non_empty_iter::NonEmptyIterator;
 use non_empty_slice::{EmptySlice, NonEmptyBytes};
 use non_zero_size::Size;
 use thiserror::Error;
 
 use crate::{
+    folding::CASE_FOLDING,
     internal::{Bytes, MutBytes, RawBytes, attempt, map_error},
     iter::{
         Bytes as BytesIter, CharIndices, Chars, EncodeUtf16, EscapeDebug, EscapeDefault,
-        EscapeUnicode, Lines, SplitAsciiWhitespace, SplitWhitespace,
+        EscapeUnicode, Lines, RSplitNonEmpty, SplitAsciiWhitespace, SplitNNonEmpty, SplitNonEmpty,
+        SplitTerminatorNonEmpty, SplitWhitespace,
     },
 };
 
@@ -93,6 +103,44 @@ pub enum MaybeEmptyUtf8Error {
     Utf8(#[from] NonEmptyUtf8Error),
 }
 
+/// The error message used when the prefix or suffix is missing.
+pub const MISSING_AFFIX: &str = "the prefix or suffix is missing";
+
+/// Represents errors returned when the string does not start with the given prefix
+/// or does not end with the given suffix.
+#[derive(Debug, Error)]
+#[error("{MISSING_AFFIX}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_str::str::affix),
+        help("make sure the string starts with the prefix or ends with the suffix")
+    )
+)]
+pub struct MissingAffix;
+
+/// Represents errors returned when stripping prefixes or suffixes from non-empty strings,
+/// either because the prefix or suffix is missing, or because the remainder would be empty.
+///
+/// This is returned from [`try_strip_prefix`] and [`try_strip_suffix`] methods on [`NonEmptyStr`].
+///
+/// [`try_strip_prefix`]: NonEmptyStr::try_strip_prefix
+/// [`try_strip_suffix`]: NonEmptyStr::try_strip_suffix
+#[derive(Debug, Error)]
+#[error(transparent)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(transparent)
+)]
+pub enum StripError {
+    /// The prefix or suffix is missing.
+    Missing(#[from] MissingAffix),
+    /// The prefix or suffix is present, but the remainder is empty.
+    Empty(#[from] EmptyStr),
+}
+
 /// Parsing values from non-empty strings.
 pub trait FromNonEmptyStr: Sized {
     /// The associated error type returned when parsing fails.
@@ -106,6 +154,105 @@ pub trait FromNonEmptyStr: Sized {
     fn from_non_empty_str(string: &NonEmptyStr) -> Result<Self, Self::Error>;
 }
 
+// NOTE: the standard library does not provide case folding, so the generated table is used;
+// characters without mappings fold to themselves
+pub(crate) fn fold_case(character: char) -> impl Iterator<Item = char> {
+    let folded = CASE_FOLDING
+        .binary_search_by_key(&character, |&(unfolded, _)| unfolded)
+        .map_or([character, '\0', '\0'], |index| CASE_FOLDING[index].1);
+
+    folded.into_iter().take_while(|&folded| folded != '\0')
+}
+
+/// Represents separators used to split strings.
+///
+/// This trait is implemented for [`char`], [`prim@str`] slices and character predicates,
+/// mirroring the corresponding implementations of the unstable [`Pattern`] trait.
+///
+/// [`Pattern`]: core::str::pattern::Pattern
+pub trait Separator: Sized {
+    /// The iterator returned by [`split`].
+    ///
+    /// [`split`]: Self::split
+    type Split<'s>: FusedIterator<Item = &'s str>;
+
+    /// The iterator returned by [`rsplit`].
+    ///
+    /// [`rsplit`]: Self::rsplit
+    type RSplit<'s>: FusedIterator<Item = &'s str>;
+
+    /// The iterator returned by [`split_terminator`].
+    ///
+    /// [`split_terminator`]: Self::split_terminator
+    type SplitTerminator<'s>: FusedIterator<Item = &'s str>;
+
+    /// The iterator returned by [`splitn`].
+    ///
+    /// [`splitn`]: Self::splitn
+    type SplitN<'s>: FusedIterator<Item = &'s str>;
+
+    /// Splits the given string by this separator, see [`str::split`].
+    fn split(self, string: &str) -> Self::Split<'_>;
+
+    /// Splits the given string by this separator in reverse order, see [`str::rsplit`].
+    fn rsplit(self, string: &str) -> Self::RSplit<'_>;
+
+    /// Splits the given string terminated by this separator, see [`str::split_terminator`].
+    fn split_terminator(self, string: &str) -> Self::SplitTerminator<'_>;
+
+    /// Splits the given string by this separator at most `count - 1` times, see [`str::splitn`].
+    fn splitn(self, string: &str, count: usize) -> Self::SplitN<'_>;
+
+    /// Splits the given string on the first occurrence of this separator,
+    /// see [`str::split_once`].
+    fn split_once(self, string: &str) -> Option<(&str, &str)>;
+
+    /// Splits the given string on the last occurrence of this separator,
+    /// see [`str::rsplit_once`].
+    fn rsplit_once(self, string: &str) -> Option<(&str, &str)>;
+}
+
+macro_rules! impl_separator {
+    ([$($generics: tt)*] $type: ty) => {
+        impl $($generics)* Separator for $type {
+            type Split<'s> = core::str::Split<'s, $type>;
+            type RSplit<'s> = core::str::RSplit<'s, $type>;
+            type SplitTerminator<'s> = core::str::SplitTerminator<'s, $type>;
+            type SplitN<'s> = core::str::SplitN<'s, $type>;
+
+            fn split(self, string: &str) -> Self::Split<'_> {
+                string.split(self)
+            }
+
+            fn rsplit(self, string: &str) -> Self::RSplit<'_> {
+                string.rsplit(self)
+            }
+
+            fn split_terminator(self, string: &str) -> Self::SplitTerminator<'_> {
+                string.split_terminator(self)
+            }
+
+            fn splitn(self, string: &str, count: usize) -> Self::SplitN<'_> {
+                string.splitn(count, self)
+            }
+
+            fn split_once(self, string: &str) -> Option<(&str, &str)> {
+                string.split_once(self)
+            }
+
+            fn rsplit_once(self, string: &str) -> Option<(&str, &str)> {
+                string.rsplit_once(self)
+            }
+        }
+    };
+}
+
+impl_separator!([] char);
+
+impl_separator!([<'p>] &'p str);
+
+impl_separator!([<F: FnMut(char) -> bool>] F);
+
 /// Represents non-empty [`str`] values.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -809,6 +956,64 @@ impl NonEmptyStr {
         F::from_non_empty_str(self)
     }
 
+    /// Returns the number of characters in this string as [`Size`].
+    ///
+    /// Note that this is different from [`len`], which returns the length in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let string = non_empty_str!("привет");
+    ///
+    /// assert_eq!(string.char_count().get(), 6);
+    /// assert_eq!(string.len().get(), 12);
+    /// ```
+    ///
+    /// [`len`]: Self::len
+    #[must_use]
+    pub fn char_count(&self) -> Size {
+        self.chars().count()
+    }
+
+    /// Returns the length of this string in UTF-16 code units as [`Size`].
+    #[must_use]
+    pub fn utf16_len(&self) -> Size {
+        self.encode_utf16().count()
+    }
+
+    /// Returns the first character of this string.
+    #[must_use]
+    pub fn first_char(&self) -> char {
+        let (character, _) = self.chars().consume();
+
+        character
+    }
+
+    /// Returns the last character of this string.
+    #[must_use]
+    pub fn last_char(&self) -> char {
+        self.as_str()
+            .chars()
+            .next_back()
+            .expect("expected non-empty string")
+    }
+
+    /// Returns the `n`-th character of this string (zero-indexed),
+    /// or [`None`] if there are not enough characters.
+    #[must_use]
+    pub fn nth_char(&self, n: usize) -> Option<char> {
+        self.as_str().chars().nth(n)
+    }
+
+    /// Returns the character starting at the given byte index,
+    /// or [`None`] if the index is out of bounds or not on character boundary.
+    #[must_use]
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        self.as_str().get(index..)?.chars().next()
+    }
+
     /// Returns non-empty iterators over the lines of this string.
     #[must_use]
     pub const fn lines(&self) -> Lines<'_> {
@@ -827,6 +1032,34 @@ impl NonEmptyStr {
         self.as_str().eq_ignore_ascii_case(other.as_str())
     }
 
+    /// Checks that the two strings are equal when compared caselessly,
+    /// using full Unicode case folding.
+    ///
+    /// See [`to_non_empty_casefold`] for details on how the folding is performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let lower = non_empty_str!("straße");
+    /// let upper = non_empty_str!("STRASSE");
+    ///
+    /// assert!(lower.eq_ignore_case(upper));
+    /// assert!(!lower.eq_ignore_ascii_case(upper));
+    ///
+    /// assert!(!non_empty_str!("ı").eq_ignore_case(non_empty_str!("i")));
+    /// ```
+    ///
+    /// [`to_non_empty_casefold`]: Self::to_non_empty_casefold
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &Self) -> bool {
+        self.as_str()
+            .chars()
+            .flat_map(fold_case)
+            .eq(other.as_str().chars().flat_map(fold_case))
+    }
+
     /// Converts the string to its ASCII uppercase equivalent in-place.
     pub const fn make_ascii_uppercase(&mut self) {
         self.as_mut_str().make_ascii_uppercase();
@@ -854,4 +1087,247 @@ impl NonEmptyStr {
     pub const fn trim_ascii(&self) -> &str {
         self.as_str().trim_ascii()
     }
+
+    /// Returns iterators over the non-empty substrings of this string,
+    /// separated by the given [`Separator`].
+    ///
+    /// Empty substrings are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let path = non_empty_str!("/usr//local/bin/");
+    ///
+    /// let segments: Vec<_> = path.split_non_empty('/').map(|segment| segment.as_str()).collect();
+    ///
+    /// assert_eq!(segments, ["usr", "local", "bin"]);
+    ///
+    /// let module = non_empty_str!("std::str::::pattern");
+    ///
+    /// let segments: Vec<_> = module.split_non_empty("::").map(|segment| segment.as_str()).collect();
+    ///
+    /// assert_eq!(segments, ["std", "str", "pattern"]);
+    /// ```
+    #[must_use]
+    pub fn split_non_empty<S: Separator>(&self, separator: S) -> SplitNonEmpty<'_, S> {
+        SplitNonEmpty::new(self, separator)
+    }
+
+    /// Similar to [`split_non_empty`], but yields substrings in reverse order.
+    ///
+    /// [`split_non_empty`]: Self::split_non_empty
+    #[must_use]
+    pub fn rsplit_non_empty<S: Separator>(&self, separator: S) -> RSplitNonEmpty<'_, S> {
+        RSplitNonEmpty::new(self, separator)
+    }
+
+    /// Returns iterators over the non-empty substrings of this string,
+    /// terminated by the given [`Separator`].
+    ///
+    /// Empty substrings are skipped.
+    #[must_use]
+    pub fn split_terminator_non_empty<S: Separator>(
+        &self,
+        separator: S,
+    ) -> SplitTerminatorNonEmpty<'_, S> {
+        SplitTerminatorNonEmpty::new(self, separator)
+    }
+
+    /// Returns iterators over the non-empty substrings of this string,
+    /// separated by the given [`Separator`], splitting at most `count - 1` times.
+    ///
+    /// Empty substrings are skipped, though they still count towards the `count` limit.
+    #[must_use]
+    pub fn splitn_non_empty<S: Separator>(
+        &self,
+        count: usize,
+        separator: S,
+    ) -> SplitNNonEmpty<'_, S> {
+        SplitNNonEmpty::new(self, count, separator)
+    }
+
+    /// Splits the string on the first occurrence of the given [`Separator`],
+    /// returning the parts before and after it, provided both are non-empty.
+    ///
+    /// Returns [`None`] if the separator is not found or either of the parts is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let pair = non_empty_str!("key=value");
+    ///
+    /// let (key, value) = pair.split_once_non_empty('=').unwrap();
+    ///
+    /// assert_eq!(key, non_empty_str!("key"));
+    /// assert_eq!(value, non_empty_str!("value"));
+    ///
+    /// let empty_key = non_empty_str!("=value");
+    ///
+    /// assert!(empty_key.split_once_non_empty('=').is_none());
+    /// ```
+    #[must_use]
+    pub fn split_once_non_empty<S: Separator>(&self, separator: S) -> Option<(&Self, &Self)> {
+        let (before, after) = separator.split_once(self.as_str())?;
+
+        Some((Self::from_str(before)?, Self::from_str(after)?))
+    }
+
+    /// Similar to [`split_once_non_empty`], but splits on the last occurrence of the separator.
+    ///
+    /// [`split_once_non_empty`]: Self::split_once_non_empty
+    #[must_use]
+    pub fn rsplit_once_non_empty<S: Separator>(&self, separator: S) -> Option<(&Self, &Self)> {
+        let (before, after) = separator.rsplit_once(self.as_str())?;
+
+        Some((Self::from_str(before)?, Self::from_str(after)?))
+    }
+
+    /// Returns the string with the given prefix removed,
+    /// or [`None`] if the prefix is missing or the remainder would be empty.
+    ///
+    /// Use [`try_strip_prefix`] in order to distinguish between the two cases.
+    ///
+    /// [`try_strip_prefix`]: Self::try_strip_prefix
+    #[must_use]
+    pub fn strip_prefix_non_empty(&self, prefix: &str) -> Option<&Self> {
+        self.try_strip_prefix(prefix).ok()
+    }
+
+    /// Returns the string with the given suffix removed,
+    /// or [`None`] if the suffix is missing or the remainder would be empty.
+    ///
+    /// Use [`try_strip_suffix`] in order to distinguish between the two cases.
+    ///
+    /// [`try_strip_suffix`]: Self::try_strip_suffix
+    #[must_use]
+    pub fn strip_suffix_non_empty(&self, suffix: &str) -> Option<&Self> {
+        self.try_strip_suffix(suffix).ok()
+    }
+
+    /// Returns the string with the given prefix removed, provided the remainder is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StripError`] if the prefix is missing or the remainder would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::{StripError, non_empty_str};
+    ///
+    /// let url = non_empty_str!("https://nekit.dev");
+    ///
+    /// assert_eq!(url.try_strip_prefix("https://").unwrap(), non_empty_str!("nekit.dev"));
+    ///
+    /// assert!(matches!(url.try_strip_prefix("ftp://"), Err(StripError::Missing(_))));
+    ///
+    /// assert!(matches!(url.try_strip_prefix(url), Err(StripError::Empty(_))));
+    /// ```
+    pub fn try_strip_prefix(&self, prefix: &str) -> Result<&Self, StripError> {
+        let remainder = self.as_str().strip_prefix(prefix).ok_or(MissingAffix)?;
+
+        let non_empty = Self::try_from_str(remainder)?;
+
+        Ok(non_empty)
+    }
+
+    /// Returns the string with the given suffix removed, provided the remainder is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StripError`] if the suffix is missing or the remainder would be empty.
+    pub fn try_strip_suffix(&self, suffix: &str) -> Result<&Self, StripError> {
+        let remainder = self.as_str().strip_suffix(suffix).ok_or(MissingAffix)?;
+
+        let non_empty = Self::try_from_str(remainder)?;
+
+        Ok(non_empty)
+    }
+
+    /// Returns new string with leading and trailing whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let padded = non_empty_str!("  nekit  ");
+    ///
+    /// assert_eq!(padded.trim_non_empty(), Some(non_empty_str!("nekit")));
+    ///
+    /// let blank = non_empty_str!(" \t\n");
+    ///
+    /// assert_eq!(blank.trim_non_empty(), None);
+    /// ```
+    #[must_use]
+    pub fn trim_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.as_str().trim())
+    }
+
+    /// Returns new string with leading whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub fn trim_start_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.as_str().trim_start())
+    }
+
+    /// Returns new string with trailing whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub fn trim_end_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.as_str().trim_end())
+    }
+
+    /// Returns new string with leading and trailing characters matching the predicate removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub fn trim_matches_non_empty<F: FnMut(char) -> bool>(&self, predicate: F) -> Option<&Self> {
+        Self::from_str(self.as_str().trim_matches(predicate))
+    }
+
+    /// Returns new string with leading characters matching the predicate removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub fn trim_start_matches_non_empty<F: FnMut(char) -> bool>(
+        &self,
+        predicate: F,
+    ) -> Option<&Self> {
+        Self::from_str(self.as_str().trim_start_matches(predicate))
+    }
+
+    /// Returns new string with trailing characters matching the predicate removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub fn trim_end_matches_non_empty<F: FnMut(char) -> bool>(
+        &self,
+        predicate: F,
+    ) -> Option<&Self> {
+        Self::from_str(self.as_str().trim_end_matches(predicate))
+    }
+
+    /// Returns new string with leading and trailing ASCII whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub const fn trim_ascii_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.trim_ascii())
+    }
+
+    /// Returns new string with leading ASCII whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub const fn trim_ascii_start_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.trim_ascii_start())
+    }
+
+    /// Returns new string with trailing ASCII whitespace removed,
+    /// or [`None`] if the resulting string would be empty.
+    #[must_use]
+    pub const fn trim_ascii_end_non_empty(&self) -> Option<&Self> {
+        Self::from_str(self.trim_ascii_end())
+    }
 }
@@ -28,11 +28,14 @@ use non_zero_size::Size;
 
 use thiserror::Error;
 
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::{
     boxed::{EmptyBoxedStr, NonEmptyBoxedStr},
     cow::NonEmptyCowStr,
     internal::{ByteVec, Bytes},
-    str::{EmptyStr, FromNonEmptyStr, NonEmptyStr, NonEmptyUtf8Error},
+    str::{EmptyStr, FromNonEmptyStr, NonEmptyStr, NonEmptyUtf8Error, StripError, fold_case},
 };
 
 /// The error message used when the string is empty.
@@ -75,6 +78,21 @@ impl EmptyString {
     }
 }
 
+// NOTE: title case is derived from the uppercase mapping, see `to_non_empty_titlecase`
+fn push_titlecase(string: &mut String, character: char) {
+    if !character.is_lowercase() && !character.is_uppercase() {
+        string.push(character);
+
+        return;
+    }
+
+    let mut uppercase = character.to_uppercase();
+
+    string.extend(uppercase.next());
+
+    string.extend(uppercase.flat_map(char::to_lowercase));
+}
+
 /// Couples [`NonEmptyUtf8Error`] with the [`NonEmptyByteVec`] that is invalid UTF-8.
 #[derive(Debug, Error)]
 #[error("{error}")]
@@ -941,12 +959,32 @@ impl NonEmptyString {
         }
     }
 
+    /// Converts this string to its lowercase equivalent in-place.
+    ///
+    /// The buffer is reused if the string is ASCII, otherwise the string is replaced.
+    pub fn make_lowercase(&mut self) {
+        if self.is_ascii() {
+            self.make_ascii_lowercase();
+        } else {
+            *self = self.to_non_empty_lowercase();
+        }
+    }
+
+    /// Converts this string to its uppercase equivalent in-place.
+    ///
+    /// The buffer is reused if the string is ASCII, otherwise the string is replaced.
+    pub fn make_uppercase(&mut self) {
+        if self.is_ascii() {
+            self.make_ascii_uppercase();
+        } else {
+            *self = self.to_non_empty_uppercase();
+        }
+    }
+
     /// Checks whether the string is almost empty, meaning it only contains one character.
     #[must_use]
     pub fn next_empty(&self) -> bool {
-        let (character, _) = self.chars().consume();
-
-        self.len().get() - character.len_utf8() == 0
+        self.len().get() - self.first_char().len_utf8() == 0
     }
 
     /// The negated version of [`next_empty`].
@@ -1030,6 +1068,83 @@ impl NonEmptyString {
             .then(|| unsafe { self.as_mut_string().remove(index) })
     }
 
+    /// Removes leading and trailing whitespace from this string in-place.
+    ///
+    /// The string is left unchanged if it would become empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyStr`] if the string consists of whitespace only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::NonEmptyString;
+    ///
+    /// let mut string: NonEmptyString = "  nekit  ".parse().unwrap();
+    ///
+    /// string.trim_in_place().unwrap();
+    ///
+    /// assert_eq!(string.as_str(), "nekit");
+    ///
+    /// let mut blank: NonEmptyString = " \t ".parse().unwrap();
+    ///
+    /// assert!(blank.trim_in_place().is_err());
+    ///
+    /// assert_eq!(blank.as_str(), " \t ");
+    /// ```
+    pub fn trim_in_place(&mut self) -> Result<(), EmptyStr> {
+        let trimmed = self.trim_non_empty().ok_or(EmptyStr)?.as_str();
+
+        let start = trimmed.as_ptr().addr() - self.as_ptr().addr();
+        let end = start + trimmed.len();
+
+        // SAFETY: the trimmed part is non-empty, so the string remains non-empty
+        unsafe {
+            let string = self.as_mut_string();
+
+            string.truncate(end);
+            string.drain(..start);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the given prefix from this string in-place,
+    /// provided the string starts with it and the remainder is non-empty.
+    ///
+    /// The string is left unchanged in case of errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StripError`] if the prefix is missing or the remainder would be empty.
+    pub fn strip_prefix_in_place(&mut self, prefix: &str) -> Result<(), StripError> {
+        self.try_strip_prefix(prefix)?;
+
+        // SAFETY: the remainder is non-empty, so the string remains non-empty
+        unsafe {
+            self.as_mut_string().drain(..prefix.len());
+        }
+
+        Ok(())
+    }
+
+    /// Removes the given suffix from this string in-place,
+    /// provided the string ends with it and the remainder is non-empty.
+    ///
+    /// The string is left unchanged in case of errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StripError`] if the suffix is missing or the remainder would be empty.
+    pub fn strip_suffix_in_place(&mut self, suffix: &str) -> Result<(), StripError> {
+        let len = self.try_strip_suffix(suffix)?.len();
+
+        self.truncate(len);
+
+        Ok(())
+    }
+
     /// Splits the string into two at the given non-zero index.
     ///
     /// The index has to be non-zero to guaratee that the string would remain non-empty.
@@ -1105,6 +1220,174 @@ impl NonEmptyStr {
         // SAFETY: converting non-empty string to uppercase gives non-empty output
         unsafe { NonEmptyString::new_unchecked(self.to_uppercase()) }
     }
+
+    /// Converts this string to its case-folded equivalent as [`NonEmptyString`],
+    /// suitable for caseless comparison.
+    ///
+    /// This uses the full Unicode case folding, as defined by `CaseFolding.txt`
+    /// (common and full mappings, without the Turkic ones).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let string = non_empty_str!("Maẞe");
+    ///
+    /// assert_eq!(string.to_non_empty_casefold().as_str(), "masse");
+    ///
+    /// let dotless = non_empty_str!("ı");
+    ///
+    /// assert_eq!(dotless.to_non_empty_casefold().as_str(), "ı");
+    /// ```
+    #[must_use]
+    pub fn to_non_empty_casefold(&self) -> NonEmptyString {
+        let folded = self.as_str().chars().flat_map(fold_case).collect();
+
+        // SAFETY: case folding non-empty string gives non-empty output
+        unsafe { NonEmptyString::new_unchecked(folded) }
+    }
+
+    /// Converts this string to its title case equivalent as [`NonEmptyString`].
+    ///
+    /// The first alphanumeric character of every word is converted to title case,
+    /// and the rest of the word is converted to lowercase.
+    ///
+    /// With the `unicode-segmentation` feature, words are found using the Unicode word
+    /// boundaries, so punctuation such as commas separates words. Otherwise, words are
+    /// separated by whitespace only. In both cases, apostrophes and combining marks
+    /// within words do not start new ones.
+    ///
+    /// The standard library does not provide the title case mapping, so it is derived
+    /// from the uppercase one: the first character of the uppercase mapping is kept,
+    /// and the rest are converted to lowercase, which handles characters such as `ß`
+    /// and ligatures. Characters that are neither uppercase nor lowercase are kept as is.
+    /// This differs from the title case mapping for Latin digraphs (`ǆ` becomes `Ǆ`
+    /// instead of `ǅ`), Greek letters with ypogegrammeni (`ᾳ` becomes `Αι` instead of `ᾼ`)
+    /// and Georgian letters, which are converted to uppercase instead of being kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let string = non_empty_str!("hello WORLD, don't cafe\u{301}s");
+    ///
+    /// assert_eq!(
+    ///     string.to_non_empty_titlecase().as_str(),
+    ///     "Hello World, Don't Cafe\u{301}s",
+    /// );
+    ///
+    /// let ligature = non_empty_str!("ﬁsh ßtraße");
+    ///
+    /// assert_eq!(ligature.to_non_empty_titlecase().as_str(), "Fish Sstraße");
+    /// ```
+    #[must_use]
+    pub fn to_non_empty_titlecase(&self) -> NonEmptyString {
+        let mut titlecase = String::with_capacity(self.len().get());
+
+        #[cfg(feature = "unicode-segmentation")]
+        let words = self.as_str().split_word_bounds();
+
+        #[cfg(not(feature = "unicode-segmentation"))]
+        let words = self.as_str().split_inclusive(char::is_whitespace);
+
+        for word in words {
+            let Some(index) = word.find(char::is_alphanumeric) else {
+                titlecase.push_str(word);
+
+                continue;
+            };
+
+            let (prefix, rest) = word.split_at(index);
+
+            let mut characters = rest.chars();
+
+            titlecase.push_str(prefix);
+
+            if let Some(character) = characters.next() {
+                push_titlecase(&mut titlecase, character);
+            }
+
+            titlecase.push_str(&characters.as_str().to_lowercase());
+        }
+
+        // SAFETY: converting non-empty string to title case gives non-empty output
+        unsafe { NonEmptyString::new_unchecked(titlecase) }
+    }
+
+    /// Replaces all matches of `from` with the non-empty `to`, returning [`NonEmptyString`].
+    ///
+    /// Since the replacement is non-empty, the resulting string is guaranteed to be non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let path = non_empty_str!("a/b/c");
+    ///
+    /// let replaced = path.replace_non_empty("/", non_empty_str!("::"));
+    ///
+    /// assert_eq!(replaced.as_str(), "a::b::c");
+    /// ```
+    #[must_use]
+    pub fn replace_non_empty(&self, from: &str, to: &Self) -> NonEmptyString {
+        let non_empty = self.as_str().replace(from, to.as_str());
+
+        // SAFETY: replacing parts of non-empty string with non-empty string
+        // results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(non_empty) }
+    }
+
+    /// Replaces first `count` matches of `from` with the non-empty `to`,
+    /// returning [`NonEmptyString`].
+    ///
+    /// Since the replacement is non-empty, the resulting string is guaranteed to be non-empty.
+    #[must_use]
+    pub fn replacen_non_empty(&self, from: &str, to: &Self, count: usize) -> NonEmptyString {
+        let non_empty = self.as_str().replacen(from, to.as_str(), count);
+
+        // SAFETY: replacing parts of non-empty string with non-empty string
+        // results in non-empty string
+        unsafe { NonEmptyString::new_unchecked(non_empty) }
+    }
+
+    /// Replaces all matches of `from` with `to`, provided the resulting string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the resulting string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    ///
+    /// let string = non_empty_str!("nekit");
+    ///
+    /// assert_eq!(string.try_replace("it", "").unwrap().as_str(), "nek");
+    ///
+    /// assert!(string.try_replace("nekit", "").is_err());
+    /// ```
+    pub fn try_replace(&self, from: &str, to: &str) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().replace(from, to))
+    }
+
+    /// Replaces first `count` matches of `from` with `to`,
+    /// provided the resulting string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the resulting string is empty.
+    pub fn try_replacen(
+        &self,
+        from: &str,
+        to: &str,
+        count: usize,
+    ) -> Result<NonEmptyString, EmptyString> {
+        NonEmptyString::new(self.as_str().replacen(from, to, count))
+    }
 }
 
 impl NonEmptyString {
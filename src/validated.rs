@@ -0,0 +1,773 @@
+//! Non-empty strings validated according to custom rules.
+
+#[cfg(feature = "std")]
+use std::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Borrow, ToOwned},
+    string::String,
+};
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::str::FromStr;
+
+#[cfg(feature = "diagnostics")]
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::str::{EmptyStr, NonEmptyStr};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::str::FromNonEmptyStr;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::string::{EmptyString, NonEmptyString};
+
+/// Validating non-empty strings.
+///
+/// Implementors are usually unit types that are used as markers for [`ValidatedStr`]
+/// and [`ValidatedString`].
+pub trait Validator {
+    /// The associated error type returned when validation fails.
+    type Error;
+
+    /// Validates the given non-empty string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the string is invalid.
+    fn validate(string: &NonEmptyStr) -> Result<(), Self::Error>;
+}
+
+/// Represents errors returned when received strings are either empty or invalid.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub enum MaybeEmptyValidationError<E> {
+    /// The received string is empty.
+    Empty(#[from] EmptyStr),
+    /// The received string is non-empty, but invalid.
+    Invalid(E),
+}
+
+/// Couples the validation error with the [`NonEmptyString`] that is invalid.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct FromValidationError<E> {
+    #[source]
+    error: E,
+    string: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<E> FromValidationError<E> {
+    // NOTE: this is private to prevent creating this error with valid strings
+    pub(crate) const fn new(error: E, string: NonEmptyString) -> Self {
+        Self { error, string }
+    }
+
+    /// Returns the underlying validation error.
+    #[must_use]
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the contained invalid string.
+    #[must_use]
+    pub fn get(self) -> NonEmptyString {
+        self.string
+    }
+
+    /// Recovers the underlying validation error and the invalid string.
+    #[must_use]
+    pub fn recover(self) -> (E, NonEmptyString) {
+        (self.error, self.string)
+    }
+}
+
+/// Similar to [`MaybeEmptyValidationError`], but holds the strings provided.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub enum FromMaybeEmptyValidationError<E> {
+    /// The received string is empty.
+    Empty(#[from] EmptyString),
+    /// The received string is non-empty, but invalid.
+    Invalid(#[from] FromValidationError<E>),
+}
+
+// NOTE: `miette::Diagnostic` can not be derived for generic errors, as the derive macro
+// does not infer `E: Diagnostic` bounds, hence the manual implementations below
+
+#[cfg(feature = "diagnostics")]
+macro_rules! delegate_diagnostic {
+    ($diagnostic: ident => $delegate: expr) => {
+        fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            let $diagnostic = self;
+
+            $delegate.code()
+        }
+
+        fn severity(&self) -> Option<Severity> {
+            let $diagnostic = self;
+
+            $delegate.severity()
+        }
+
+        fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            let $diagnostic = self;
+
+            $delegate.help()
+        }
+
+        fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            let $diagnostic = self;
+
+            $delegate.url()
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            let $diagnostic = self;
+
+            $delegate.source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            let $diagnostic = self;
+
+            $delegate.labels()
+        }
+
+        fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+            let $diagnostic = self;
+
+            $delegate.related()
+        }
+
+        fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+            let $diagnostic = self;
+
+            $delegate.diagnostic_source()
+        }
+    };
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for MaybeEmptyValidationError<E> {
+    delegate_diagnostic!(error => match error {
+        Self::Empty(empty) => empty as &dyn Diagnostic,
+        Self::Invalid(invalid) => invalid as &dyn Diagnostic,
+    });
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for FromValidationError<E> {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("non_empty_str::validated::string"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("make sure the string is valid"))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for FromMaybeEmptyValidationError<E> {
+    delegate_diagnostic!(error => match error {
+        Self::Empty(empty) => empty as &dyn Diagnostic,
+        Self::Invalid(invalid) => invalid as &dyn Diagnostic,
+    });
+}
+
+/// Represents non-empty [`str`] values validated by `V`.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_str::{NonEmptyStr, validated::{ValidatedStr, Validator}};
+///
+/// #[derive(Debug)]
+/// struct InvalidUsername;
+///
+/// struct Username;
+///
+/// impl Validator for Username {
+///     type Error = InvalidUsername;
+///
+///     fn validate(string: &NonEmptyStr) -> Result<(), Self::Error> {
+///         string
+///             .as_str()
+///             .chars()
+///             .all(|character| character.is_ascii_alphanumeric())
+///             .then_some(())
+///             .ok_or(InvalidUsername)
+///     }
+/// }
+///
+/// let username = ValidatedStr::<Username>::try_from_str("nekitdev").unwrap();
+///
+/// assert_eq!(username.as_str(), "nekitdev");
+///
+/// assert!(ValidatedStr::<Username>::try_from_str("nekit dev").is_err());
+/// ```
+#[repr(transparent)]
+pub struct ValidatedStr<V> {
+    // NOTE: `fn() -> V` is used so that auto traits do not depend on `V`
+    validator: PhantomData<fn() -> V>,
+    inner: NonEmptyStr,
+}
+
+impl<V> fmt::Debug for ValidatedStr<V> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl<V> fmt::Display for ValidatedStr<V> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+impl<V> PartialEq for ValidatedStr<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_non_empty_str() == other.as_non_empty_str()
+    }
+}
+
+impl<V> Eq for ValidatedStr<V> {}
+
+impl<V> PartialOrd for ValidatedStr<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> Ord for ValidatedStr<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_non_empty_str().cmp(other.as_non_empty_str())
+    }
+}
+
+impl<V> Hash for ValidatedStr<V> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_non_empty_str().hash(hasher);
+    }
+}
+
+impl<'s, V: Validator> TryFrom<&'s str> for &'s ValidatedStr<V> {
+    type Error = MaybeEmptyValidationError<V::Error>;
+
+    fn try_from(string: &'s str) -> Result<Self, Self::Error> {
+        ValidatedStr::try_from_str(string)
+    }
+}
+
+impl<'s, V: Validator> TryFrom<&'s NonEmptyStr> for &'s ValidatedStr<V> {
+    type Error = V::Error;
+
+    fn try_from(non_empty: &'s NonEmptyStr) -> Result<Self, Self::Error> {
+        ValidatedStr::try_from_non_empty_str(non_empty)
+    }
+}
+
+impl<'s, V> From<&'s ValidatedStr<V>> for &'s NonEmptyStr {
+    fn from(validated: &'s ValidatedStr<V>) -> Self {
+        validated.as_non_empty_str()
+    }
+}
+
+impl<'s, V> From<&'s ValidatedStr<V>> for &'s str {
+    fn from(validated: &'s ValidatedStr<V>) -> Self {
+        validated.as_str()
+    }
+}
+
+impl<V> AsRef<Self> for ValidatedStr<V> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<V> AsRef<NonEmptyStr> for ValidatedStr<V> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl<V> AsRef<str> for ValidatedStr<V> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<V> Deref for ValidatedStr<V> {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl<V: Validator> ValidatedStr<V> {
+    /// Constructs [`Self`] from anything that can be converted to string,
+    /// provided it is non-empty and valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyValidationError`] if the string is empty or invalid.
+    pub fn try_new<S: AsRef<str> + ?Sized>(
+        string: &S,
+    ) -> Result<&Self, MaybeEmptyValidationError<V::Error>> {
+        Self::try_from_str(string.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<str> + ?Sized>(string: &S) -> Option<&Self> {
+        Self::from_str(string.as_ref())
+    }
+
+    /// Constructs [`Self`] from [`str`], provided the string is non-empty and valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MaybeEmptyValidationError`] if the string is empty or invalid.
+    pub fn try_from_str(string: &str) -> Result<&Self, MaybeEmptyValidationError<V::Error>> {
+        let non_empty = NonEmptyStr::try_from_str(string)?;
+
+        Self::try_from_non_empty_str(non_empty).map_err(MaybeEmptyValidationError::Invalid)
+    }
+
+    /// Similar to [`try_from_str`], but the error is discarded.
+    ///
+    /// [`try_from_str`]: Self::try_from_str
+    // NOTE: this can not be `const` since validation is done by `Validator` implementations;
+    // `FromStr` does not apply, as `ValidatedStr` is unsized
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn from_str(string: &str) -> Option<&Self> {
+        Self::try_from_str(string).ok()
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], provided the string is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`V::Error`] if the string is invalid.
+    ///
+    /// [`V::Error`]: Validator::Error
+    pub fn try_from_non_empty_str(non_empty: &NonEmptyStr) -> Result<&Self, V::Error> {
+        V::validate(non_empty)?;
+
+        // SAFETY: the string is non-empty and valid at this point
+        Ok(unsafe { Self::from_non_empty_str_unchecked(non_empty) })
+    }
+}
+
+impl<V> ValidatedStr<V> {
+    /// Constructs [`Self`] from anything that can be converted to string, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and valid.
+    #[must_use]
+    pub unsafe fn new_unchecked<S: AsRef<str> + ?Sized>(string: &S) -> &Self {
+        // SAFETY: the caller must ensure that the string is non-empty and valid
+        unsafe { Self::from_str_unchecked(string.as_ref()) }
+    }
+
+    /// Constructs [`Self`] from [`str`], without checking if the string is non-empty and valid.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and valid.
+    #[must_use]
+    pub const unsafe fn from_str_unchecked(string: &str) -> &Self {
+        debug_assert!(!string.is_empty());
+
+        // SAFETY: the caller must ensure that the string is non-empty and valid
+        // `Self` is `repr(transparent)` over `NonEmptyStr`, which is `repr(transparent)`
+        // over `str`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(string) as *const Self) }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyStr`], without checking if the string is valid.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is valid.
+    #[must_use]
+    pub const unsafe fn from_non_empty_str_unchecked(non_empty: &NonEmptyStr) -> &Self {
+        // SAFETY: the caller must ensure that the string is valid
+        unsafe { Self::from_str_unchecked(non_empty.as_str()) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        &self.inner
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is deprecated since the string is never empty.
+    #[deprecated = "this string is never empty"]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.inner.len()
+    }
+}
+
+/// Represents non-empty [`String`] values validated by `V`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[repr(transparent)]
+pub struct ValidatedString<V> {
+    validator: PhantomData<fn() -> V>,
+    inner: NonEmptyString,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Clone for ValidatedString<V> {
+    fn clone(&self) -> Self {
+        // SAFETY: the string is non-empty and valid by construction
+        unsafe { Self::from_non_empty_string_unchecked(self.inner.clone()) }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> fmt::Debug for ValidatedString<V> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> fmt::Display for ValidatedString<V> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_non_empty_str().fmt(formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> PartialEq for ValidatedString<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_validated_str() == other.as_validated_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Eq for ValidatedString<V> {}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> PartialOrd for ValidatedString<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Ord for ValidatedString<V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_validated_str().cmp(other.as_validated_str())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Hash for ValidatedString<V> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_validated_str().hash(hasher);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> FromStr for ValidatedString<V> {
+    type Err = MaybeEmptyValidationError<V::Error>;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        ValidatedStr::try_from_str(string).map(Self::from_validated_str)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> FromNonEmptyStr for ValidatedString<V> {
+    type Error = V::Error;
+
+    fn from_non_empty_str(string: &NonEmptyStr) -> Result<Self, Self::Error> {
+        ValidatedStr::try_from_non_empty_str(string).map(Self::from_validated_str)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> ToOwned for ValidatedStr<V> {
+    type Owned = ValidatedString<V>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_validated_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Borrow<ValidatedStr<V>> for ValidatedString<V> {
+    fn borrow(&self) -> &ValidatedStr<V> {
+        self.as_validated_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Borrow<NonEmptyStr> for ValidatedString<V> {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Borrow<str> for ValidatedString<V> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> TryFrom<String> for ValidatedString<V> {
+    type Error = FromMaybeEmptyValidationError<V::Error>;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> TryFrom<NonEmptyString> for ValidatedString<V> {
+    type Error = FromValidationError<V::Error>;
+
+    fn try_from(non_empty: NonEmptyString) -> Result<Self, Self::Error> {
+        Self::from_non_empty_string(non_empty)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> TryFrom<&str> for ValidatedString<V> {
+    type Error = MaybeEmptyValidationError<V::Error>;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> TryFrom<&NonEmptyStr> for ValidatedString<V> {
+    type Error = V::Error;
+
+    fn try_from(non_empty: &NonEmptyStr) -> Result<Self, Self::Error> {
+        non_empty.parse_non_empty()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> From<&ValidatedStr<V>> for ValidatedString<V> {
+    fn from(validated: &ValidatedStr<V>) -> Self {
+        Self::from_validated_str(validated)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> From<ValidatedString<V>> for NonEmptyString {
+    fn from(validated: ValidatedString<V>) -> Self {
+        validated.into_non_empty_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> From<ValidatedString<V>> for String {
+    fn from(validated: ValidatedString<V>) -> Self {
+        validated.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> AsRef<Self> for ValidatedString<V> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> AsRef<ValidatedStr<V>> for ValidatedString<V> {
+    fn as_ref(&self) -> &ValidatedStr<V> {
+        self.as_validated_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> AsRef<NonEmptyStr> for ValidatedString<V> {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> AsRef<str> for ValidatedString<V> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> Deref for ValidatedString<V> {
+    type Target = ValidatedStr<V>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_validated_str()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V: Validator> ValidatedString<V> {
+    /// Constructs [`Self`], provided that the [`String`] is non-empty and valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromMaybeEmptyValidationError`] if the string is empty or invalid.
+    pub fn new(string: String) -> Result<Self, FromMaybeEmptyValidationError<V::Error>> {
+        let non_empty = NonEmptyString::new(string)?;
+
+        let validated = Self::from_non_empty_string(non_empty)?;
+
+        Ok(validated)
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyString`], provided it is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromValidationError`] if the string is invalid.
+    pub fn from_non_empty_string(
+        non_empty: NonEmptyString,
+    ) -> Result<Self, FromValidationError<V::Error>> {
+        if let Err(error) = V::validate(&non_empty) {
+            return Err(FromValidationError::new(error, non_empty));
+        }
+
+        // SAFETY: the string is valid at this point
+        Ok(unsafe { Self::from_non_empty_string_unchecked(non_empty) })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> ValidatedString<V> {
+    /// Constructs [`Self`] without checking if the [`String`] is non-empty and valid.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty and valid.
+    #[must_use]
+    pub const unsafe fn new_unchecked(string: String) -> Self {
+        // SAFETY: the caller must ensure that the string is non-empty and valid
+        unsafe { Self::from_non_empty_string_unchecked(NonEmptyString::new_unchecked(string)) }
+    }
+
+    /// Constructs [`Self`] without checking if the [`NonEmptyString`] is valid.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is valid.
+    #[must_use]
+    pub const unsafe fn from_non_empty_string_unchecked(inner: NonEmptyString) -> Self {
+        Self {
+            validator: PhantomData,
+            inner,
+        }
+    }
+
+    /// Constructs [`Self`] from [`ValidatedStr`] via cloning.
+    #[must_use]
+    pub fn from_validated_str(validated: &ValidatedStr<V>) -> Self {
+        // SAFETY: the string is valid by construction
+        unsafe { Self::from_non_empty_string_unchecked(validated.to_non_empty_string()) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Returns the contained string as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        self.inner.as_non_empty_str()
+    }
+
+    /// Returns the contained string as [`ValidatedStr`].
+    #[must_use]
+    pub const fn as_validated_str(&self) -> &ValidatedStr<V> {
+        // SAFETY: the string is non-empty and valid by construction
+        unsafe { ValidatedStr::from_non_empty_str_unchecked(self.as_non_empty_str()) }
+    }
+
+    /// Returns the contained [`NonEmptyString`].
+    #[must_use]
+    pub const fn as_non_empty_string(&self) -> &NonEmptyString {
+        &self.inner
+    }
+
+    /// Returns the contained [`String`].
+    #[must_use]
+    pub const fn as_string(&self) -> &String {
+        self.inner.as_string()
+    }
+
+    /// Converts [`Self`] into the contained [`NonEmptyString`].
+    #[must_use]
+    pub fn into_non_empty_string(self) -> NonEmptyString {
+        self.inner
+    }
+
+    /// Converts [`Self`] into the contained [`String`].
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner.into_string()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<V> ValidatedStr<V> {
+    /// Converts [`Self`] into [`ValidatedString`].
+    #[must_use]
+    pub fn to_validated_string(&self) -> ValidatedString<V> {
+        ValidatedString::from_validated_str(self)
+    }
+}
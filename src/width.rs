@@ -0,0 +1,162 @@
+//! Display width of non-empty strings.
+
+#[cfg(not(feature = "unicode-width"))]
+compile_error!("expected `unicode-width` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::String};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, string::String};
+
+use non_zero_size::Size;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{cow::NonEmptyCowStr, str::NonEmptyStr, string::NonEmptyString};
+
+/// Represents alignments used when padding strings to the given display width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Alignment {
+    /// The string is aligned to the left, with padding added to the right.
+    #[default]
+    Left,
+    /// The string is aligned to the right, with padding added to the left.
+    Right,
+    /// The string is centered, with padding added to both sides.
+    ///
+    /// If the padding can not be split evenly, the extra space is added to the right.
+    Center,
+}
+
+impl Alignment {
+    const fn split(self, padding: usize) -> (usize, usize) {
+        match self {
+            Self::Left => (0, padding),
+            Self::Right => (padding, 0),
+            Self::Center => {
+                let left = padding / 2;
+
+                (left, padding - left)
+            }
+        }
+    }
+}
+
+/// The character used for padding.
+pub const PADDING: char = ' ';
+
+impl NonEmptyStr {
+    /// Returns the display width of this string in columns.
+    ///
+    /// Characters in the ambiguous East Asian width category are treated as narrow,
+    /// see [`display_width_cjk`] for CJK contexts.
+    ///
+    /// Note that the width can be zero, for instance, if the string consists
+    /// of zero-width characters only.
+    ///
+    /// [`display_width_cjk`]: Self::display_width_cjk
+    #[must_use]
+    pub fn display_width(&self) -> usize {
+        self.as_str().width()
+    }
+
+    /// Returns the display width of this string in columns, treating characters
+    /// in the ambiguous East Asian width category as wide, as is customary in CJK contexts.
+    #[must_use]
+    pub fn display_width_cjk(&self) -> usize {
+        self.as_str().width_cjk()
+    }
+
+    /// Truncates this string to fit into `max` columns, appending the given `ellipsis`
+    /// in case any truncation happens.
+    ///
+    /// Grapheme clusters are never split. The string is borrowed if it already fits.
+    ///
+    /// Note that if the `ellipsis` itself does not fit, it is returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::non_empty_str;
+    /// use non_zero_size::Size;
+    ///
+    /// let string = non_empty_str!("nekitdev");
+    /// let ellipsis = non_empty_str!("...");
+    ///
+    /// let truncated = string.truncate_to_width(Size::new(6).unwrap(), ellipsis);
+    ///
+    /// assert_eq!(truncated.as_str(), "nek...");
+    ///
+    /// let fits = string.truncate_to_width(Size::new(8).unwrap(), ellipsis);
+    ///
+    /// assert_eq!(fits.as_str(), "nekitdev");
+    /// ```
+    #[must_use]
+    pub fn truncate_to_width(&self, max: Size, ellipsis: &Self) -> NonEmptyCowStr<'_> {
+        let max = max.get();
+
+        if self.display_width() <= max {
+            return Cow::Borrowed(self);
+        }
+
+        let available = max.saturating_sub(ellipsis.display_width());
+
+        let mut width = 0;
+
+        let end = self
+            .as_str()
+            .grapheme_indices(true)
+            .find_map(|(index, grapheme)| {
+                width += grapheme.width();
+
+                (width > available).then_some(index)
+            })
+            .unwrap_or(self.len().get());
+
+        let mut truncated = String::with_capacity(end + ellipsis.len().get());
+
+        truncated.push_str(&self.as_str()[..end]);
+        truncated.push_str(ellipsis.as_str());
+
+        // SAFETY: the ellipsis is non-empty, so is the truncated string
+        Cow::Owned(unsafe { NonEmptyString::new_unchecked(truncated) })
+    }
+
+    /// Pads this string with spaces to be at least `width` columns wide,
+    /// according to the given `alignment`.
+    ///
+    /// The string is borrowed if it is already wide enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_str::{non_empty_str, width::Alignment};
+    ///
+    /// let string = non_empty_str!("nekit");
+    ///
+    /// assert_eq!(string.pad_to_width(9, Alignment::Left).as_str(), "nekit    ");
+    /// assert_eq!(string.pad_to_width(9, Alignment::Right).as_str(), "    nekit");
+    /// assert_eq!(string.pad_to_width(9, Alignment::Center).as_str(), "  nekit  ");
+    /// ```
+    #[must_use]
+    pub fn pad_to_width(&self, width: usize, alignment: Alignment) -> NonEmptyCowStr<'_> {
+        let Some(padding) = width
+            .checked_sub(self.display_width())
+            .filter(|&padding| padding > 0)
+        else {
+            return Cow::Borrowed(self);
+        };
+
+        let (left, right) = alignment.split(padding);
+
+        let mut padded = String::with_capacity(self.len().get() + padding);
+
+        padded.extend((0..left).map(|_| PADDING));
+        padded.push_str(self.as_str());
+        padded.extend((0..right).map(|_| PADDING));
+
+        // SAFETY: padding non-empty string results in non-empty string
+        Cow::Owned(unsafe { NonEmptyString::new_unchecked(padded) })
+    }
+}
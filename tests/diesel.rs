@@ -0,0 +1,82 @@
+#![cfg(feature = "diesel")]
+
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    dsl::sql,
+    result::{DeserializeFieldError, Error},
+    select, sql_query,
+    sql_types::Text,
+    table,
+};
+
+use non_empty_str::{EmptyString, NonEmptyBoxedStr, NonEmptyString, non_empty_str};
+
+table! {
+    names (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+fn connect() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+    sql_query("CREATE TABLE names (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .execute(&mut connection)
+        .unwrap();
+
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let mut connection = connect();
+
+    let string = non_empty_str!("nekit");
+
+    diesel::insert_into(names::table)
+        .values(names::name.eq(string))
+        .execute(&mut connection)
+        .unwrap();
+
+    let owned: NonEmptyString = names::table
+        .select(names::name)
+        .filter(names::name.eq(string))
+        .first(&mut connection)
+        .unwrap();
+
+    assert_eq!(owned.as_non_empty_str(), string);
+
+    let boxed: NonEmptyBoxedStr = names::table
+        .select(names::name)
+        .filter(names::name.eq(&owned))
+        .first(&mut connection)
+        .unwrap();
+
+    assert_eq!(&*boxed, string);
+
+    let count: i64 = names::table
+        .filter(names::name.eq(boxed))
+        .count()
+        .get_result(&mut connection)
+        .unwrap();
+
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn decode_empty() {
+    let mut connection = connect();
+
+    let error = select(sql::<Text>("''"))
+        .get_result::<NonEmptyString>(&mut connection)
+        .unwrap_err();
+
+    let Error::DeserializationError(source) = error else {
+        panic!("expected deserialization error, got {error}");
+    };
+
+    let field = source.downcast_ref::<DeserializeFieldError>().unwrap();
+
+    assert!(field.error.downcast_ref::<EmptyString>().is_some());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "rusqlite")]
+
+use non_empty_str::{
+    EmptyString, FromMaybeEmptyUtf8Error, NonEmptyBoxedStr, NonEmptyString, non_empty_str,
+};
+use rusqlite::{
+    Connection, Error,
+    types::{FromSql, FromSqlError, ValueRef},
+};
+
+fn connect() -> Connection {
+    Connection::open_in_memory().unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let connection = connect();
+
+    let string = non_empty_str!("nekit");
+
+    let owned: NonEmptyString = connection
+        .query_row("SELECT ?1", [string], |row| row.get(0))
+        .unwrap();
+
+    assert_eq!(owned.as_non_empty_str(), string);
+
+    let boxed: NonEmptyBoxedStr = connection
+        .query_row("SELECT ?1", [&owned], |row| row.get(0))
+        .unwrap();
+
+    assert_eq!(&*boxed, string);
+
+    let owned_again: NonEmptyString = connection
+        .query_row("SELECT ?1", [&boxed], |row| row.get(0))
+        .unwrap();
+
+    assert_eq!(owned_again, owned);
+}
+
+#[test]
+fn decode_blob() {
+    let connection = connect();
+
+    let string: NonEmptyString = connection
+        .query_row("SELECT CAST('nekit' AS BLOB)", [], |row| row.get(0))
+        .unwrap();
+
+    assert_eq!(string.as_non_empty_str(), non_empty_str!("nekit"));
+
+    let error = connection
+        .query_row("SELECT X''", [], |row| row.get::<_, NonEmptyString>(0))
+        .unwrap_err();
+
+    let Error::FromSqlConversionFailure(_, _, source) = error else {
+        panic!("expected conversion failure, got {error}");
+    };
+
+    assert!(source.downcast_ref::<FromMaybeEmptyUtf8Error>().is_some());
+}
+
+#[test]
+fn decode_empty() {
+    let connection = connect();
+
+    let error = connection
+        .query_row("SELECT ''", [], |row| row.get::<_, NonEmptyString>(0))
+        .unwrap_err();
+
+    let Error::FromSqlConversionFailure(_, _, source) = error else {
+        panic!("expected conversion failure, got {error}");
+    };
+
+    assert!(source.downcast_ref::<EmptyString>().is_some());
+
+    let result = NonEmptyString::column_result(ValueRef::Text(b""));
+
+    assert!(matches!(result, Err(FromSqlError::Other(_))));
+}
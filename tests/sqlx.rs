@@ -0,0 +1,86 @@
+#![cfg(feature = "sqlx")]
+
+use futures_executor::block_on;
+use non_empty_str::{
+    EmptyStr, EmptyString, NonEmptyBoxedStr, NonEmptyStr, NonEmptyString, non_empty_str,
+};
+use sqlx::{Connection, Row, SqliteConnection, query, query_scalar};
+
+async fn connect() -> SqliteConnection {
+    SqliteConnection::connect("sqlite::memory:").await.unwrap()
+}
+
+#[test]
+fn round_trip() {
+    block_on(async {
+        let mut connection = connect().await;
+
+        let string = non_empty_str!("nekit");
+
+        let owned: NonEmptyString = query_scalar("SELECT ?")
+            .bind(string)
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(owned.as_non_empty_str(), string);
+
+        let boxed: NonEmptyBoxedStr = query_scalar("SELECT ?")
+            .bind(owned.clone())
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(&*boxed, string);
+
+        let owned_again: NonEmptyString = query_scalar("SELECT ?")
+            .bind(boxed)
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(owned_again, owned);
+    });
+}
+
+#[test]
+fn decode_borrowed() {
+    block_on(async {
+        let mut connection = connect().await;
+
+        let row = query("SELECT 'nekit', ''")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+
+        let string: &NonEmptyStr = row.try_get(0).unwrap();
+
+        assert_eq!(string, non_empty_str!("nekit"));
+
+        let error = row.try_get::<&NonEmptyStr, _>(1).unwrap_err();
+
+        let sqlx::Error::ColumnDecode { source, .. } = error else {
+            panic!("expected column decode error, got {error}");
+        };
+
+        assert!(source.downcast_ref::<EmptyStr>().is_some());
+    });
+}
+
+#[test]
+fn decode_empty() {
+    block_on(async {
+        let mut connection = connect().await;
+
+        let error = query_scalar::<_, NonEmptyString>("SELECT ''")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap_err();
+
+        let sqlx::Error::ColumnDecode { source, .. } = error else {
+            panic!("expected column decode error, got {error}");
+        };
+
+        assert!(source.downcast_ref::<EmptyString>().is_some());
+    });
+}